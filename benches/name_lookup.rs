@@ -0,0 +1,144 @@
+//! Compares the bucketed `variant_for_name` against a naive linear string match.
+//!
+//! Run with `cargo bench --bench name_lookup`.
+
+use rawenum::rawenum;
+use std::hint::black_box;
+use std::time::Instant;
+
+#[rawenum(u8, name_lookup)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Opcode {
+    Nop,
+    Load,
+    Store,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    And,
+    Or,
+    Xor,
+    Not,
+    Shl,
+    Shr,
+    Sar,
+    Rol,
+    Ror,
+    Jmp,
+    Jz,
+    Jnz,
+    Jl,
+    Jg,
+    Jle,
+    Jge,
+    Call,
+    Ret,
+    Push,
+    Pop,
+    Cmp,
+    Test,
+    Inc,
+    Dec,
+    Neg,
+    Mov,
+    Lea,
+    Xchg,
+    Halt,
+    Int,
+    Iret,
+    Cli,
+    Sti,
+    In,
+    Out,
+    Loop,
+    Enter,
+    Leave,
+}
+
+// The lookup as it would be written by hand, comparing against every name in turn
+fn naive_variant_for_name(name: &str) -> Option<Opcode> {
+    match name {
+        "Nop" => Some(Opcode::Nop),
+        "Load" => Some(Opcode::Load),
+        "Store" => Some(Opcode::Store),
+        "Add" => Some(Opcode::Add),
+        "Sub" => Some(Opcode::Sub),
+        "Mul" => Some(Opcode::Mul),
+        "Div" => Some(Opcode::Div),
+        "Rem" => Some(Opcode::Rem),
+        "And" => Some(Opcode::And),
+        "Or" => Some(Opcode::Or),
+        "Xor" => Some(Opcode::Xor),
+        "Not" => Some(Opcode::Not),
+        "Shl" => Some(Opcode::Shl),
+        "Shr" => Some(Opcode::Shr),
+        "Sar" => Some(Opcode::Sar),
+        "Rol" => Some(Opcode::Rol),
+        "Ror" => Some(Opcode::Ror),
+        "Jmp" => Some(Opcode::Jmp),
+        "Jz" => Some(Opcode::Jz),
+        "Jnz" => Some(Opcode::Jnz),
+        "Jl" => Some(Opcode::Jl),
+        "Jg" => Some(Opcode::Jg),
+        "Jle" => Some(Opcode::Jle),
+        "Jge" => Some(Opcode::Jge),
+        "Call" => Some(Opcode::Call),
+        "Ret" => Some(Opcode::Ret),
+        "Push" => Some(Opcode::Push),
+        "Pop" => Some(Opcode::Pop),
+        "Cmp" => Some(Opcode::Cmp),
+        "Test" => Some(Opcode::Test),
+        "Inc" => Some(Opcode::Inc),
+        "Dec" => Some(Opcode::Dec),
+        "Neg" => Some(Opcode::Neg),
+        "Mov" => Some(Opcode::Mov),
+        "Lea" => Some(Opcode::Lea),
+        "Xchg" => Some(Opcode::Xchg),
+        "Halt" => Some(Opcode::Halt),
+        "Int" => Some(Opcode::Int),
+        "Iret" => Some(Opcode::Iret),
+        "Cli" => Some(Opcode::Cli),
+        "Sti" => Some(Opcode::Sti),
+        "In" => Some(Opcode::In),
+        "Out" => Some(Opcode::Out),
+        "Loop" => Some(Opcode::Loop),
+        "Enter" => Some(Opcode::Enter),
+        "Leave" => Some(Opcode::Leave),
+        _ => None,
+    }
+}
+
+const ITERATIONS: u32 = 1_000_000;
+
+// A mix of matching and non-matching names
+const INPUTS: &[&str] = &[
+    "Nop", "Add", "Div", "Or", "Shl", "Rol", "Jz", "Jg", "Call", "Pop", "Inc", "Mov", "Halt",
+    "Cli", "Out", "Leave", "Bogus", "Jump", "Leaves", "X",
+];
+
+// Runs the lookup over all inputs repeatedly and prints the time taken
+fn bench(label: &str, lookup: impl Fn(&str) -> Option<Opcode>) {
+    let start = Instant::now();
+    let mut hits = 0u64;
+    for _ in 0..ITERATIONS {
+        for input in INPUTS {
+            if lookup(black_box(input)).is_some() {
+                hits += 1;
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+    let lookups = ITERATIONS as f64 * INPUTS.len() as f64;
+    println!(
+        "{label}: {:?} total, {:.2} ns/lookup ({hits} hits)",
+        elapsed,
+        elapsed.as_nanos() as f64 / lookups
+    );
+}
+
+fn main() {
+    bench("naive", naive_variant_for_name);
+    bench("bucketed", Opcode::variant_for_name);
+}
@@ -0,0 +1,73 @@
+//! Compares the default match-based `from_u8` against the `table` strategy.
+//!
+//! Run with `cargo bench --bench table`.
+
+use rawenum::rawenum;
+use std::hint::black_box;
+use std::time::Instant;
+
+#[rawenum(u8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MatchOpcode {
+    Nop = 0,
+    Load,
+    Store,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+    Jmp,
+    Jz,
+    Call,
+    Ret,
+}
+
+#[rawenum(u8, table)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TableOpcode {
+    Nop = 0,
+    Load,
+    Store,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+    Jmp,
+    Jz,
+    Call,
+    Ret,
+}
+
+const ITERATIONS: u32 = 10_000_000;
+
+// Runs the conversion over every `u8` value repeatedly and prints the time taken
+fn bench(label: &str, convert: impl Fn(u8) -> bool) {
+    let start = Instant::now();
+    let mut hits = 0u64;
+    for i in 0..ITERATIONS {
+        if convert(black_box(i as u8)) {
+            hits += 1;
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{label}: {:?} total, {:.2} ns/iter ({hits} hits)",
+        elapsed,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    bench("match", |value| MatchOpcode::from_u8(value).is_some());
+    bench("table", |value| TableOpcode::from_u8(value).is_some());
+}
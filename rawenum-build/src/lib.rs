@@ -0,0 +1,156 @@
+//! Generates enums along with the conversions of the `rawenum` macro from build scripts,
+//! for enums listed in external registries, e.g. IANA port numbers parsed from a CSV
+//! file. The code is generated once by the build script rather than by the macro on
+//! every build, keeping huge enums out of proc-macro expansion time.
+//!
+//! ```no_run
+//! // build.rs
+//! use rawenum_build::EnumSpec;
+//!
+//! fn main() {
+//!     EnumSpec::new("Port", "u16, named")
+//!         .attribute("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+//!         .variant("Http", 80)
+//!         .variant("Https", 443)
+//!         .write_to_out_dir("port.rs")
+//!         .unwrap();
+//! }
+//! ```
+//!
+//! The generated file is then included with
+//! `include!(concat!(env!("OUT_DIR"), "/port.rs"));`. The code it holds doesn't refer
+//! to `rawenum`, so the crate including it doesn't need a dependency on it.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, DeriveInput, parse::Parser};
+
+use rawenum_codegen::codegen;
+use rawenum_codegen::options::Options;
+
+/// The error returned when generating an enum fails.
+#[derive(Debug)]
+pub enum Error {
+    /// The spec is invalid, e.g. an argument or a variant name can't be parsed, or the
+    /// macro rejects the enum.
+    Invalid(String),
+    /// Writing the generated file failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(message) => write!(f, "invalid enum spec: {}", message),
+            Self::Io(error) => write!(f, "failed to write the generated enum: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<syn::Error> for Error {
+    fn from(error: syn::Error) -> Self {
+        Self::Invalid(error.to_string())
+    }
+}
+
+/// A public enum to generate: its name, the arguments of the `rawenum` macro, any other
+/// attributes, and its variants along with their values.
+#[derive(Debug, Clone)]
+pub struct EnumSpec {
+    name: String,
+    arguments: String,
+    attributes: Vec<String>,
+    variants: Vec<(String, i128)>,
+}
+
+impl EnumSpec {
+    /// Creates a spec for an enum named `name`, with `arguments` given to the `rawenum`
+    /// macro, e.g. `"u16, named"`.
+    pub fn new(name: &str, arguments: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            arguments: arguments.to_string(),
+            attributes: Vec::new(),
+            variants: Vec::new(),
+        }
+    }
+
+    /// Adds an attribute to the enum, e.g. `"#[derive(Debug)]"` or `"#[repr(u16)]"`.
+    pub fn attribute(mut self, attribute: &str) -> Self {
+        self.attributes.push(attribute.to_string());
+        self
+    }
+
+    /// Adds a variant with the given name and discriminant.
+    pub fn variant(mut self, name: &str, value: i128) -> Self {
+        self.variants.push((name.to_string(), value));
+        self
+    }
+
+    /// Adds variants with the given names and discriminants, in order.
+    pub fn variants<'a>(mut self, variants: impl IntoIterator<Item = (&'a str, i128)>) -> Self {
+        self.variants.extend(
+            variants
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value)),
+        );
+        self
+    }
+
+    /// Generates the enum along with its conversions, as Rust source code.
+    pub fn generate(&self) -> Result<String, Error> {
+        let name = syn::parse_str::<syn::Ident>(&self.name)?;
+        let attributes = self
+            .attributes
+            .iter()
+            .map(|attribute| Attribute::parse_outer.parse_str(attribute))
+            .collect::<syn::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten();
+        let variants = self
+            .variants
+            .iter()
+            .map(|(variant, value)| {
+                let variant = syn::parse_str::<syn::Ident>(variant)?;
+                // Literals can't be negative, so negative values are negated literals
+                let literal = proc_macro2::Literal::u128_unsuffixed(value.unsigned_abs());
+                let value = if *value < 0 {
+                    quote! { -#literal }
+                } else {
+                    quote! { #literal }
+                };
+                Ok(quote! { #variant = #value })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let input: DeriveInput = syn::parse2(quote! {
+            #( #attributes )*
+            pub enum #name {
+                #( #variants ),*
+            }
+        })?;
+        let options: Options = syn::parse_str(&self.arguments)?;
+        let generated: TokenStream = codegen::generate(&input, &options)?;
+
+        Ok(format!(
+            "// Generated by rawenum-build, don't edit by hand\n{}\n",
+            generated
+        ))
+    }
+
+    /// Generates the enum along with its conversions, and writes it to `file_name` in the
+    /// `OUT_DIR` of the build script, returning the path of the written file.
+    pub fn write_to_out_dir(&self, file_name: &str) -> Result<PathBuf, Error> {
+        let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+            Error::Invalid("`OUT_DIR` isn't set, which is only done for build scripts".into())
+        })?;
+        let path = PathBuf::from(out_dir).join(file_name);
+        std::fs::write(&path, self.generate()?).map_err(Error::Io)?;
+        Ok(path)
+    }
+}
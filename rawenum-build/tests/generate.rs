@@ -0,0 +1,39 @@
+use rawenum_build::{EnumSpec, Error};
+
+#[test]
+fn test_generate_enum() {
+    let generated = EnumSpec::new("Port", "u16, named")
+        .attribute("#[derive(Debug, Clone, Copy)]")
+        .variant("Http", 80)
+        .variants([("Https", 443), ("Negative", -1)])
+        .generate()
+        .unwrap();
+
+    // The output is a valid file holding the enum and its conversions
+    let file = syn::parse_file(&generated).unwrap();
+    let names: Vec<String> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Enum(item_enum) => Some(item_enum.ident.to_string()),
+            syn::Item::Impl(_) => Some("impl".to_string()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names, ["Port", "impl"]);
+    assert!(generated.contains("fn from_u16"));
+    assert!(generated.contains("fn from_u16_named"));
+    assert!(generated.contains("Negative = - 1"));
+    // The variant-level attributes and the macro itself aren't referred to
+    assert!(!generated.contains("rawenum ("));
+}
+
+#[test]
+fn test_invalid_specs() {
+    let error = EnumSpec::new("Port", "u7").variant("Http", 80).generate();
+    assert!(matches!(error, Err(Error::Invalid(message)) if message.contains("u7")));
+    let error = EnumSpec::new("Port", "u16")
+        .variant("not a name", 80)
+        .generate();
+    assert!(matches!(error, Err(Error::Invalid(_))));
+}
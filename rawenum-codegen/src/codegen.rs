@@ -0,0 +1,5492 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Deref;
+
+use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, ExprLit, ExprUnary, Fields, Ident,
+    ItemImpl, Lit, LitInt, LitStr, Meta, MetaNameValue, Token, Type, UnOp, Variant, ext::IdentExt,
+    parse_quote, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned,
+};
+
+use crate::options::{Decode, Options, RemoteEnum, Strategy, TargetType, type_ident};
+
+// The options along with the state the macro computes from the enum rather than reads
+// from the attribute
+#[derive(Clone)]
+struct Context {
+    options: Options,
+    // The variant marked `#[rawenum(catch_all)]`, which unmatched values convert to
+    catch_all: Option<Ident>,
+    // The type of the value held by the catch-all variant, if it holds the unmatched value
+    catch_all_payload: Option<Type>,
+    // The primitive `repr` of an enum whose catch-all variant holds the unmatched value,
+    // through which the discriminants are read
+    data_repr: Option<Type>,
+    // The enum's primitive representation, which the `from_*_unchecked` methods are
+    // generated for
+    unchecked_repr: Option<Ident>,
+    // The enum's primitive representation along with the first and the last value of the
+    // contiguous run formed by the discriminants of the convertible variants, if they form
+    // one, which the conversions then match with a range check
+    contiguous_run: Option<(Ident, i128, i128)>,
+    // The values of the discriminants the macro could evaluate, by variant name
+    literal_discriminants: BTreeMap<String, i128>,
+}
+
+impl Deref for Context {
+    type Target = Options;
+
+    fn deref(&self) -> &Options {
+        &self.options
+    }
+}
+
+// `strategy = "auto"` matches enums with fewer values than this
+const AUTO_MATCH_MAX_VALUES: u128 = 32;
+
+// `strategy = "auto"` looks enums with at least this many values up in a perfect hash map
+const AUTO_PHF_MIN_VALUES: u128 = 1024;
+
+// Enums with at least this many convertible variants match the values once, in the
+// conversions from the widest requested type, which those from the narrower types call
+const SHARED_MATCH_MIN_VARIANTS: usize = 256;
+
+// Supported integer types for validation
+const SUPPORTED_TYPES: &[&str] = &[
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize", "bool",
+];
+
+// The C type aliases from `core::ffi`, which map to the platform-correct primitive
+const FFI_TYPES: &[&str] = &[
+    "c_char",
+    "c_schar",
+    "c_uchar",
+    "c_short",
+    "c_ushort",
+    "c_int",
+    "c_uint",
+    "c_long",
+    "c_ulong",
+    "c_longlong",
+    "c_ulonglong",
+];
+
+/// Generates the enum definition along with all of the methods requested by `options`.
+///
+/// This is the core of the `rawenum` attribute macro, which is a thin wrapper around it.
+pub fn generate(input: &DeriveInput, options: &Options) -> syn::Result<TokenStream> {
+    // With the `values_from` option, the variants are read from a file and appended to
+    // the enum's own, after which they're handled like hand-written ones
+    let loaded_input;
+    let mut values_file = None;
+    let input = match &options.values_from {
+        Some(file) if matches!(input.data, Data::Enum(_)) => {
+            // The derive can't add variants to the enum the compiler emits
+            if options.derive {
+                return Err(syn::Error::new_spanned(
+                    file,
+                    "the `values_from` option cannot be used with the derive, use the attribute instead",
+                ));
+            }
+            let (path, variants) = load_variants(file)?;
+            let mut extended = input.clone();
+            if let Data::Enum(DataEnum {
+                variants: enum_variants,
+                ..
+            }) = &mut extended.data
+            {
+                enum_variants.extend(variants);
+            }
+            loaded_input = extended;
+            values_file = Some(path);
+            &loaded_input
+        }
+        _ => input,
+    };
+    let name = &input.ident; // The name of the enum
+
+    // With the `repr` or `num_enum` options, or when no types are given, the type is read
+    // from the enum's `#[repr(...)]` attribute, so the two can't drift apart
+    let repr_options;
+    let options = if options.repr || options.num_enum || options.types.is_empty() {
+        let Some(repr) = primitive_repr(&input.attrs) else {
+            let message = if options.repr {
+                "the `repr` option requires the enum to have a primitive representation, e.g. `#[repr(u8)]`"
+            } else if options.num_enum {
+                "the `num_enum` option requires the enum to have a primitive representation, e.g. `#[repr(u8)]`"
+            } else {
+                // Ensure at least one type was specified
+                "at least one integer type must be specified, e.g., #[rawenum(i32)]"
+            };
+            return Err(syn::Error::new_spanned(input, message));
+        };
+        let mut types = options.types.clone();
+        if !types
+            .iter()
+            .any(|target| type_ident(&target.ty) == Some(&repr))
+        {
+            types.push(TargetType {
+                ty: parse_quote!(#repr),
+                cfg: None,
+            });
+        }
+        repr_options = Options {
+            types,
+            ..options.clone()
+        };
+        &repr_options
+    } else {
+        options
+    };
+
+    // Ensure the input is an enum (or a newtype wrapping one), otherwise return a compile error.
+    let variants = match &input.data {
+        Data::Enum(DataEnum { variants, .. }) => variants,
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) if fields.unnamed.len() == 1 => {
+            return generate_newtype(input, &fields.unnamed[0].ty, options);
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "rawenum can only be applied to enums and single-field tuple structs wrapping them",
+            ));
+        }
+    };
+
+    // The variants are read from the enum itself, so they can't also be listed
+    if options.variants.is_some() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`variants(...)` is only used when applying rawenum to an impl block",
+        ));
+    }
+
+    let all_variants: Vec<&Variant> = variants.iter().collect();
+    let (all_generated_methods, mut generated_items) =
+        generate_methods(input, &all_variants, options)?;
+
+    // The lookup table copies its entries out, which otherwise only surfaces as a move
+    // error inside the generated code. The derives may be listed before the attribute or
+    // `Copy` implemented by hand, neither of which the macro sees, so the compiler checks
+    // it, pointing at the enum.
+    if options.table {
+        generated_items.push(quote_spanned! {name.span()=>
+            const _: fn() = || {
+                fn assert_copy<T: ::core::marker::Copy>() {}
+                assert_copy::<#name>();
+            };
+        });
+    }
+
+    if options.strict_types {
+        generated_items.push(check_strict_types(&all_variants, options)?);
+    }
+
+    // Including the file makes the compiler rebuild the enum whenever it changes
+    if let Some(path) = values_file {
+        generated_items.push(quote! {
+            const _: &[u8] = include_bytes!(#path);
+        });
+    }
+
+    // The enum as emitted, without the variant-level `#[rawenum(...)]` attributes, which
+    // are only read by the macro. The derive can't emit it, since the compiler already does.
+    let mut enum_def = input.clone();
+    if let Data::Enum(DataEnum { variants, .. }) = &mut enum_def.data {
+        for variant in variants {
+            variant
+                .attrs
+                .retain(|attr| !attr.path().is_ident("rawenum"));
+        }
+    }
+    let enum_def = (!options.derive).then_some(enum_def);
+
+    // With the `module` option, the methods become free functions in a module named by it
+    if let Some(module) = &options.module {
+        let vis = &input.vis;
+        let free_functions = all_generated_methods
+            .into_iter()
+            .map(|method| into_free_function(method, name, &all_variants));
+        return Ok(quote! {
+            #enum_def // Include the original enum definition
+
+            #[allow(non_snake_case)]
+            #vis mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #( #free_functions )*
+            }
+
+            #( #generated_items )*
+        });
+    }
+
+    // Combine the original enum definition and the generated methods within the impl block.
+    Ok(quote! {
+        #enum_def // Include the original enum definition
+
+        // Mark the impl as generated code for tooling such as coverage and lints
+        #[automatically_derived]
+        impl #name {
+            #( #all_generated_methods )* // Expand all the generated methods
+        }
+
+        #( #generated_items )*
+    })
+}
+
+// Reads the variants of the `values_from` option from its file, which holds a `NAME =
+// VALUE` line for each variant, along with blank lines and `#` comments. Returns the
+// absolute path of the file along with the variants.
+fn load_variants(file: &LitStr) -> syn::Result<(String, Vec<Variant>)> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+        syn::Error::new_spanned(
+            file,
+            "`CARGO_MANIFEST_DIR` isn't set, so the file can't be located",
+        )
+    })?;
+    let path = std::path::Path::new(&manifest_dir).join(file.value());
+    let contents = std::fs::read_to_string(&path).map_err(|error| {
+        syn::Error::new_spanned(
+            file,
+            format!("failed to read `{}`: {}", path.display(), error),
+        )
+    })?;
+
+    let mut variants = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let variant = syn::parse_str::<Variant>(line)
+            .ok()
+            .filter(|variant| {
+                variant.attrs.is_empty()
+                    && matches!(variant.fields, Fields::Unit)
+                    && variant.discriminant.is_some()
+            })
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    file,
+                    format!(
+                        "line {} of `{}` isn't of the form `NAME = VALUE`: `{}`",
+                        index + 1,
+                        file.value(),
+                        line
+                    ),
+                )
+            })?;
+        variants.push(variant);
+    }
+    Ok((path.display().to_string(), variants))
+}
+
+// Generates the methods for the enum's impl block, along with the items generated
+// alongside it, for the given variants of the enum
+fn generate_methods(
+    input: &DeriveInput,
+    all_variants: &[&Variant],
+    options: &Options,
+) -> syn::Result<(Vec<TokenStream>, Vec<TokenStream>)> {
+    let name = &input.ident; // The name of the enum
+
+    // Ensure the `wire` overrides and aliases are valid, and that no two variants claim
+    // the same value
+    let mut wire_values: BTreeMap<i128, &Ident> = BTreeMap::new();
+    for variant in all_variants {
+        let variant_options = variant_options(variant)?;
+        for (wire, value) in variant_options.wire.iter().chain(&variant_options.aliases) {
+            if let Some(other) = wire_values.insert(*value, &variant.ident) {
+                return Err(syn::Error::new_spanned(
+                    wire,
+                    format!("`{}` already has the wire value {}", other, value),
+                ));
+            }
+        }
+    }
+
+    // Unmatched values convert to the catch-all variant, if one is marked
+    let mut catch_all_variants = all_variants.iter().filter(|variant| is_catch_all(variant));
+    let catch_all_variant = catch_all_variants.next();
+    if let (Some(catch_all), Some(other)) = (catch_all_variant, catch_all_variants.next()) {
+        return Err(syn::Error::new_spanned(
+            &other.ident,
+            format!("`{}` is already the catch-all variant", catch_all.ident),
+        ));
+    }
+
+    // Unmatched values are converted to the catch-all variant, so it can't be skipped
+    if let Some(catch_all) = catch_all_variant
+        && is_skipped(catch_all)
+    {
+        return Err(syn::Error::new_spanned(
+            &catch_all.ident,
+            "the catch-all variant cannot be skipped",
+        ));
+    }
+
+    // A catch-all variant can carry the unmatched value
+    let mut catch_all_payload = None;
+    let mut data_repr = None;
+    if let Some(catch_all) = catch_all_variant {
+        match &catch_all.fields {
+            Fields::Unit => {}
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                // Such an enum can't be cast to an integer, so its discriminants are read
+                // through its `repr`
+                let Some(repr) = primitive_repr(&input.attrs) else {
+                    return Err(syn::Error::new_spanned(
+                        &catch_all.ident,
+                        "a catch-all variant holding the raw value requires a primitive representation on the enum, e.g. `#[repr(u32)]`",
+                    ));
+                };
+                let payload = &fields.unnamed[0].ty;
+                check_catch_all_payload(payload, options)?;
+                catch_all_payload = Some(payload.clone());
+                data_repr = Some(parse_quote! { #repr });
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &catch_all.fields,
+                    "a catch-all variant can only hold the raw value, in a single unnamed field",
+                ));
+            }
+        }
+    }
+
+    // The unchecked conversions transmute raw values into the enum, so the enum must be
+    // laid out as one of the types
+    let mut unchecked_repr = None;
+    if options.unchecked {
+        let repr = match primitive_repr(&input.attrs) {
+            Some(repr) if catch_all_payload.is_none() => repr,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "the `unchecked` option requires a fieldless enum with a primitive representation, e.g. `#[repr(u8)]`",
+                ));
+            }
+        };
+        if !options
+            .types
+            .iter()
+            .any(|target| type_ident(&target.ty) == Some(&repr))
+        {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "the `unchecked` option requires the enum's representation `{}` to be one of the types",
+                    repr
+                ),
+            ));
+        }
+        unchecked_repr = Some(repr);
+    }
+    // The discriminants the macro can evaluate, which the checks and the strategies that
+    // need the values at macro time use. When applying the macro to an impl block, the
+    // discriminants aren't visible at all.
+    let literal_discriminants = match &options.variants {
+        Some(_) => BTreeMap::new(),
+        None => all_variants
+            .iter()
+            .zip(literal_discriminants(all_variants).unwrap_or_default())
+            .filter_map(|(variant, discriminant)| Some((variant.ident.to_string(), discriminant?)))
+            .collect(),
+    };
+    let options = &Context {
+        options: options.clone(),
+        catch_all: catch_all_variant.map(|variant| variant.ident.clone()),
+        catch_all_payload,
+        data_repr,
+        unchecked_repr,
+        contiguous_run: None,
+        literal_discriminants,
+    };
+
+    // The count and the names cover every variant, including a catch-all variant holding
+    // the raw value
+    let variant_count = options
+        .variant_count
+        .then(|| generate_variant_count(all_variants));
+    let variant_name = options
+        .variant_name
+        .then(|| generate_variant_name(all_variants, options));
+
+    // A catch-all variant holding the raw value isn't a unit variant, so it is left out of
+    // the variants that the methods are generated for, and handled by them separately
+    let unit_variants: Vec<&Variant>;
+    let all_variants = if options.catch_all_payload.is_some() {
+        unit_variants = all_variants
+            .iter()
+            .filter(|variant| !is_catch_all(variant))
+            .copied()
+            .collect();
+        &unit_variants[..]
+    } else {
+        all_variants
+    };
+
+    // These options list the variants in arrays, whose lengths can't depend on a `cfg`
+    if let Some(variant) = all_variants
+        .iter()
+        .find(|variant| !variant_cfgs(variant).is_empty())
+    {
+        for (option, enabled) in [
+            ("table", options.table),
+            ("assert_distinct", options.assert_distinct),
+            ("sorted_entries", options.sorted_entries),
+            ("discriminants", options.discriminants),
+            ("discriminant_range", options.discriminant_range),
+            ("phf", options.phf),
+            ("try_from", options.try_from),
+            ("property_test", options.property_test),
+            ("iter", options.iter),
+            ("ordinals", options.ordinals),
+            ("from_index", options.from_index),
+            ("name_lookup", options.name_lookup),
+            ("strict_types", options.strict_types),
+            // `auto` falls back to the match when some values are gated behind a `cfg`
+            (
+                "strategy",
+                !matches!(options.strategy, Strategy::Match | Strategy::Auto),
+            ),
+        ] {
+            if enabled {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "the `{}` option cannot be used with variants gated behind a `cfg`",
+                        option
+                    ),
+                ));
+            }
+        }
+    }
+
+    // These options treat every variant as a unit variant
+    for (option, enabled) in [
+        ("saturating", options.saturating),
+        ("ord_by_value", options.ord_by_value),
+        ("slice_helpers", options.slice_helpers),
+        ("serde", options.serde),
+        ("num_traits", options.num_traits),
+        ("ordinals", options.ordinals),
+        ("strict_types", options.strict_types),
+    ] {
+        if options.catch_all_payload.is_some() && enabled {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "a catch-all variant holding the raw value and the `{}` option cannot be used together",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // Only the variants listed in `only(...)` (if given) are matched by the conversions
+    let convertible_variants: Vec<&Variant> = match &options.only {
+        Some(only) => {
+            // Ensure every listed variant exists
+            if let Some(unknown) = only
+                .iter()
+                .find(|ident| !all_variants.iter().any(|variant| variant.ident == **ident))
+            {
+                return Err(syn::Error::new_spanned(
+                    unknown,
+                    format!("`{}` has no variant named `{}`", name, unknown),
+                ));
+            }
+            all_variants
+                .iter()
+                .filter(|variant| only.contains(&variant.ident))
+                .copied()
+                .collect()
+        }
+        None => all_variants.to_vec(),
+    };
+    // Variants marked `#[rawenum(skip)]` are never matched either
+    let convertible_variants: Vec<&Variant> = convertible_variants
+        .into_iter()
+        .filter(|variant| !is_skipped(variant))
+        .collect();
+
+    let contiguous_run = contiguous_run(input, &convertible_variants, options);
+    let strategy = match options.strategy {
+        Strategy::Auto => auto_strategy(&convertible_variants, contiguous_run.is_some(), options),
+        strategy => strategy,
+    };
+    let options = &Context {
+        options: Options {
+            strategy,
+            ..options.options.clone()
+        },
+        contiguous_run,
+        ..options.clone()
+    };
+
+    if options.strategy == Strategy::Phf {
+        // The perfect hash map is built from the values, so the macro must know them
+        if let Some(variant) = convertible_variants
+            .iter()
+            .find(|variant| literal_values(variant, options).is_none())
+        {
+            return Err(syn::Error::new_spanned(
+                variant.discriminant.as_ref().map(|(_, expr)| expr),
+                "the `phf` strategy requires integer literal discriminants",
+            ));
+        }
+
+        // The lookup in the perfect hash map isn't a `const fn`
+        if options.const_fn {
+            return Err(syn::Error::new_spanned(
+                input,
+                "the `const` option and the `phf` strategy cannot be used together",
+            ));
+        }
+    }
+
+    // The table is a strategy of its own
+    if options.table && options.strategy != Strategy::Match {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `table` option and the `strategy` option cannot be used together",
+        ));
+    }
+
+    // The lookup table is only supported for `from_u8`, where it covers the whole input range
+    if options.table
+        && !options
+            .types
+            .iter()
+            .any(|target| type_ident(&target.ty).is_some_and(|ident| ident == "u8"))
+    {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `table` option requires the u8 type",
+        ));
+    }
+
+    // The perfect hash map only affects the name lookup
+    if options.phf && !options.name_lookup {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `phf` option requires the `name_lookup` option",
+        ));
+    }
+
+    // The perfect hash map is keyed by the exact names
+    if options.phf && options.case_insensitive {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `phf` and `case_insensitive` options cannot be used together",
+        ));
+    }
+
+    // Case insensitivity only affects the name lookup
+    if options.case_insensitive && !options.name_lookup {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `case_insensitive` option requires the `name_lookup` option",
+        ));
+    }
+
+    // Total conversions panic on the values the range check and the test expect to be rejected
+    for (option, enabled) in [
+        ("try_from", options.try_from),
+        ("property_test", options.property_test),
+    ] {
+        if options.total && enabled {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "the `total` and `{}` options cannot be used together",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // Every value converts to the catch-all variant, so no value is rejected
+    for (option, enabled) in [
+        ("total", options.total),
+        ("table", options.table),
+        ("property_test", options.property_test),
+    ] {
+        if options.catch_all.is_some() && enabled {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "a `catch_all` variant and the `{}` option cannot be used together",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // These options implement `TryFrom` or `From` for the representation as well
+    for (option, enabled) in [
+        ("try_from", options.try_from),
+        ("impl_from", options.impl_from),
+    ] {
+        if options.num_enum && enabled {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "the `num_enum` and `{}` options cannot be used together",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // The constructor builds the custom error, so there must be one
+    if let Some(error_fn) = &options.error_fn
+        && options.error.is_none()
+    {
+        return Err(syn::Error::new_spanned(
+            error_fn,
+            "the `error_fn` option requires a custom error type, e.g. `error = MyError`",
+        ));
+    }
+
+    // Const conversions can't call the trace hook or the error constructor, read the table
+    // or format a panic message
+    for (option, enabled) in [
+        ("trace", options.trace.is_some()),
+        ("error", options.error.is_some()),
+        ("table", options.table),
+        ("total", options.total),
+    ] {
+        if options.const_fn && enabled {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "the `const` and `{}` options cannot be used together",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // The strict guards leave values that the exhaustiveness check and the table can't see
+    for (option, enabled) in [
+        ("exhaustive", options.exhaustive),
+        ("exhaustive_methods", options.exhaustive_methods),
+        ("table", options.table),
+    ] {
+        if options.strict && enabled {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "the `strict` and `{}` options cannot be used together",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // The `From` impls can't truncate the values that `deny_lossy` rejects
+    if options.lossy && options.deny_lossy {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `lossy` and `deny_lossy` options cannot be used together",
+        ));
+    }
+
+    // Only the `From` impls can be lossy
+    if options.lossy && !options.impl_from {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `lossy` option requires the `impl_from` option",
+        ));
+    }
+
+    // Without the type in their names, the consts of different types would clash
+    if let Some(scheme) = &options.consts
+        && options.types.len() > 1
+        && !scheme.value().contains("{TYPE}")
+    {
+        return Err(syn::Error::new_spanned(
+            scheme,
+            "the name scheme must contain `{TYPE}` when several types are requested",
+        ));
+    }
+
+    // The table is filled from the variants' own values only
+    if options.table
+        && let Some(variant) = all_variants
+            .iter()
+            .find(|variant| !alias_values(variant).is_empty())
+    {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            "the `table` option cannot be used with aliases",
+        ));
+    }
+
+    // The table body returns an `Option`, so it can't be used for a conversion returning `Self`
+    if options.table && options.total {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `table` and `total` options cannot be used together",
+        ));
+    }
+
+    // The table body returns an `Option`, so it can't be used for an exhaustive conversion
+    if options.table && options.exhaustive {
+        return Err(syn::Error::new_spanned(
+            input,
+            "the `table` and `exhaustive` options cannot be used together",
+        ));
+    }
+
+    // This vector will collect the code for all generated methods.
+    let mut all_generated_methods = Vec::new();
+
+    // The type whose match the conversions from the narrower types may share
+    let shared_type = shared_match_type(&convertible_variants, options)?;
+
+    // Generate the `from_*` functions (and friends) only for specified types
+    for target in &options.types {
+        all_generated_methods.push(generate_type_methods(
+            name,
+            all_variants,
+            &convertible_variants,
+            target,
+            shared_type.as_ref(),
+            options,
+        )?);
+    }
+
+    if options.name_lookup {
+        all_generated_methods.push(generate_name_lookup(all_variants, options)?);
+    }
+
+    if options.ord_by_value {
+        all_generated_methods.push(generate_cmp_by_discriminant(all_variants));
+    }
+
+    if options.from_index {
+        all_generated_methods.push(generate_from_index(all_variants));
+    }
+
+    if options.ordinals {
+        all_generated_methods.push(generate_ordinals(all_variants));
+    }
+
+    all_generated_methods.extend(variant_count);
+    all_generated_methods.extend(variant_name);
+
+    if options.discriminant_range {
+        let values = all_variants
+            .iter()
+            .map(|variant| {
+                let raw_value = raw_value(quote! { Self }, variant, options);
+                quote! { #raw_value as i128 }
+            })
+            .collect();
+        all_generated_methods.extend(generate_discriminant_range(values, &quote! { i128 }, ""));
+    }
+
+    if options.primary {
+        all_generated_methods.push(generate_primary_const(options)?);
+    }
+
+    if options.generic {
+        all_generated_methods.push(generate_generic_from_raw(options));
+    }
+
+    // Items generated alongside the methods
+    let mut generated_items = Vec::new();
+
+    if options.deny_lossy {
+        generated_items.push(generate_deny_lossy(name, all_variants, options)?);
+    }
+
+    // `decode_*` is skipped for the types too narrow for the tag field, but at least one
+    // type must hold it
+    if let Some(decode) = &options.decode {
+        let mut fits_any = false;
+        for target in &options.types {
+            fits_any |= decode_fits(decode, &ConversionType::new(&target.ty, options)?);
+        }
+        if !fits_any {
+            return Err(syn::Error::new(
+                decode.span,
+                "the tag field doesn't fit in any of the types, so no `decode_*` method could be generated",
+            ));
+        }
+    }
+
+    if options.deny_collisions {
+        for target in &options.types {
+            generated_items.extend(check_collisions(
+                name,
+                &convertible_variants,
+                target,
+                options,
+            )?);
+        }
+    }
+
+    if options.iter {
+        let (iter_method, iter_items) = generate_iter(input, all_variants);
+        all_generated_methods.push(iter_method);
+        generated_items.push(iter_items);
+    }
+
+    if options.try_from {
+        generated_items.push(generate_try_from(input, &convertible_variants, options)?);
+    }
+
+    if options.result {
+        generated_items.push(generate_invalid_raw_value(
+            input,
+            &convertible_variants,
+            options,
+        )?);
+    }
+
+    if options.traits {
+        generated_items.push(generate_raw_traits(input, options)?);
+    }
+
+    if options.serde {
+        generated_items.push(generate_serde(input, all_variants, options)?);
+    }
+
+    if options.num_enum {
+        generated_items.push(generate_num_enum(input, options)?);
+    }
+
+    if options.num_traits {
+        generated_items.push(generate_num_traits(
+            input,
+            all_variants,
+            &convertible_variants,
+            options,
+        ));
+    }
+
+    if options.impl_from {
+        generated_items.push(generate_from_enum_impls(input, all_variants, options)?);
+    }
+
+    if options.roundtrip_test {
+        generated_items.push(generate_roundtrip_test(
+            name,
+            &convertible_variants,
+            options,
+        )?);
+    }
+
+    if options.property_test {
+        generated_items.push(generate_property_test(
+            input,
+            &convertible_variants,
+            options,
+        )?);
+    }
+
+    Ok((all_generated_methods, generated_items))
+}
+
+/// Generates the extension trait providing the `from_*` methods for an enum defined in
+/// another crate, along with const assertions checking that the listed values are the
+/// discriminants of its variants.
+///
+/// This is the core of the `rawenum_remote` macro. The enum can't be annotated, so its
+/// variants and their values are listed in the macro.
+pub fn generate_remote(remote: &RemoteEnum) -> syn::Result<TokenStream> {
+    let RemoteEnum {
+        path,
+        types,
+        variants,
+    } = remote;
+    let Some(enum_name) = path.segments.last().map(|segment| &segment.ident) else {
+        return Err(syn::Error::new_spanned(
+            path,
+            "expected the path of the enum",
+        ));
+    };
+    let trait_name = format_ident!("{}RawExt", enum_name.unraw());
+    let trait_doc = format!(
+        " The conversions from raw integer values to [`{}`], which is defined in another crate.",
+        enum_name.unraw()
+    );
+
+    // The listed values must be the discriminants, since those are what the methods match
+    let checks = variants.iter().map(|(variant, value)| {
+        let message = format!(
+            "the discriminant of `{}::{}` isn't the listed value",
+            enum_name.unraw(),
+            variant.unraw()
+        );
+        // Point a failing assertion at the listed value
+        quote_spanned! {value.span()=>
+            #[allow(clippy::unnecessary_cast)]
+            const _: () = assert!(#path::#variant as i128 == (#value) as i128, #message);
+        }
+    });
+
+    let options = Options::default();
+    let mut signatures = Vec::new();
+    let mut methods = Vec::new();
+    for ty in types {
+        let conversion_type = ConversionType::new(ty, &options)?;
+        let specified_type = &conversion_type.ty;
+        let const_type = conversion_type.const_type();
+        let match_value = conversion_type.match_value();
+        let fn_name = conversion_type.method_name("from_", "");
+
+        // The discriminants are cast to the type like in the other conversions
+        let const_names: Vec<Ident> = variants
+            .iter()
+            .map(|(variant, _)| {
+                format_ident!(
+                    "__RAWENUM_DISCRIMINANT_{}",
+                    variant.unraw().to_string().to_uppercase()
+                )
+            })
+            .collect();
+        let variant_names: Vec<&Ident> = variants.iter().map(|(variant, _)| variant).collect();
+
+        signatures.push(quote! {
+            /// Converts a raw #specified_type integer value to the variant with the
+            /// matching discriminant (when cast to #specified_type).
+            ///
+            /// Returns `None` if `value` doesn't match any variant.
+            #[must_use]
+            fn #fn_name(value: #specified_type) -> Option<Self>;
+        });
+        methods.push(quote! {
+            fn #fn_name(value: #specified_type) -> Option<Self> {
+                #(
+                    const #const_names: #const_type = #path::#variant_names as i128 as #const_type;
+                )*
+                match #match_value {
+                    #( #const_names => Some(#path::#variant_names), )*
+                    _ => None,
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #trait_doc]
+        #[allow(dead_code)]
+        pub trait #trait_name: Sized {
+            #( #signatures )*
+        }
+
+        #[automatically_derived]
+        impl #trait_name for #path {
+            #( #methods )*
+        }
+
+        #( #checks )*
+    })
+}
+
+/// Generates the methods requested by `options` into an existing inherent impl block of
+/// an enum, e.g. `#[rawenum(u8, variants(A, B))] impl MyEnum { ... }`, alongside the
+/// hand-written methods.
+///
+/// The enum definition isn't visible from the impl block, so the variants are listed
+/// in the attribute. Their discriminants are only ever evaluated by the compiler, so
+/// their names are all that is needed.
+pub fn generate_impl(item_impl: &ItemImpl, options: &Options) -> syn::Result<TokenStream> {
+    // The enum definition, and with it its `#[repr(...)]`, isn't visible from here
+    if options.repr {
+        return Err(syn::Error::new_spanned(
+            item_impl.impl_token,
+            "the `repr` option can't be used when applying rawenum to an impl block",
+        ));
+    }
+
+    // Ensure at least one type was specified
+    if options.types.is_empty() {
+        return Err(syn::Error::new_spanned(
+            item_impl.impl_token,
+            "at least one integer type must be specified, e.g., #[rawenum(i32)]",
+        ));
+    }
+
+    // Only plain inherent impls of a named type can hold the generated methods
+    if item_impl.trait_.is_some()
+        || item_impl.unsafety.is_some()
+        || !item_impl.generics.params.is_empty()
+    {
+        return Err(syn::Error::new_spanned(
+            item_impl.impl_token,
+            "rawenum can only be applied to inherent impl blocks without generics",
+        ));
+    }
+    let Some(name) = type_ident(&item_impl.self_ty) else {
+        return Err(syn::Error::new_spanned(
+            &item_impl.self_ty,
+            "expected the name of the enum, e.g. `impl MyEnum`",
+        ));
+    };
+
+    let Some(variants) = &options.variants else {
+        return Err(syn::Error::new_spanned(
+            item_impl.impl_token,
+            "the variants of the enum must be listed when applying rawenum to an impl block, e.g. #[rawenum(u8, variants(A, B))]",
+        ));
+    };
+
+    // The generated methods live in the impl block, so they can't be moved to a module
+    if let Some(module) = &options.module {
+        return Err(syn::Error::new_spanned(
+            module,
+            "the `module` option cannot be used when applying rawenum to an impl block",
+        ));
+    }
+
+    // The discriminants are only visible in the enum definition
+    if options.strict_types {
+        return Err(syn::Error::new_spanned(
+            item_impl.impl_token,
+            "the `strict_types` option cannot be used when applying rawenum to an impl block",
+        ));
+    }
+
+    // The representation and the variants are only visible in the enum definition
+    for (option, enabled) in [
+        ("unchecked", options.unchecked),
+        ("num_enum", options.num_enum),
+        ("values_from", options.values_from.is_some()),
+        ("strategy", options.strategy != Strategy::Match),
+    ] {
+        if enabled {
+            return Err(syn::Error::new_spanned(
+                item_impl.impl_token,
+                format!(
+                    "the `{}` option cannot be used when applying rawenum to an impl block",
+                    option
+                ),
+            ));
+        }
+    }
+
+    // A stand-in for the enum definition, holding only what the generated code uses
+    let input: DeriveInput = parse_quote! {
+        enum #name {
+            #( #variants ),*
+        }
+    };
+    let Data::Enum(DataEnum {
+        variants: input_variants,
+        ..
+    }) = &input.data
+    else {
+        unreachable!()
+    };
+    let all_variants: Vec<&Variant> = input_variants.iter().collect();
+    let (all_generated_methods, generated_items) =
+        generate_methods(&input, &all_variants, options)?;
+
+    let ItemImpl {
+        attrs,
+        self_ty,
+        items,
+        ..
+    } = item_impl;
+    Ok(quote! {
+        #( #attrs )*
+        impl #self_ty {
+            #( #items )* // Keep the hand-written items
+
+            #( #all_generated_methods )* // Expand all the generated methods
+        }
+
+        #( #generated_items )*
+    })
+}
+
+// Rewrites an item generated for the enum's impl block into a free item, for the
+// `module` option:
+// - Paths to associated items, `Self::f`, become paths to the sibling free items,
+//   `self::f`. These are told apart from paths to variants by their names.
+// - Any other `Self` is replaced with the enum's name.
+// - `self` receivers are replaced with a `this: &Enum` parameter, so `fn f(&self)`
+//   becomes `fn f(this: &Enum)`.
+fn into_free_function(tokens: TokenStream, name: &Ident, variants: &[&Variant]) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut output = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        output.push(match token {
+            TokenTree::Ident(ident) if ident == "Self" => {
+                let is_item_path = matches!(
+                    &tokens[i + 1..],
+                    [TokenTree::Punct(first), TokenTree::Punct(second), TokenTree::Ident(item), ..]
+                        if first.as_char() == ':'
+                            && second.as_char() == ':'
+                            && !variants.iter().any(|variant| variant.ident == *item)
+                );
+                if is_item_path {
+                    TokenTree::Ident(Ident::new("self", ident.span()))
+                } else {
+                    let mut name = name.clone();
+                    name.set_span(ident.span());
+                    TokenTree::Ident(name)
+                }
+            }
+            TokenTree::Ident(ident) if ident == "self" => {
+                TokenTree::Ident(Ident::new("this", ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut stream = group.stream();
+                // A parameter list starting with `&self` is a receiver
+                let mut inner = group.stream().into_iter();
+                if group.delimiter() == Delimiter::Parenthesis
+                    && matches!(inner.next(), Some(TokenTree::Punct(punct)) if punct.as_char() == '&')
+                    && matches!(inner.next(), Some(TokenTree::Ident(ident)) if ident == "self")
+                {
+                    let rest: TokenStream = inner.collect();
+                    stream = quote! { this: &#name #rest };
+                }
+                let mut rewritten =
+                    Group::new(group.delimiter(), into_free_function(stream, name, variants));
+                rewritten.set_span(group.span());
+                TokenTree::Group(rewritten)
+            }
+            token => token.clone(),
+        });
+    }
+    output.into_iter().collect()
+}
+
+// Generates the methods for a newtype struct wrapping a `rawenum` enum, e.g.
+// `struct Tag(MyEnum);`. Each method delegates to the matching method of the inner
+// enum and wraps its result.
+fn generate_newtype(
+    input: &DeriveInput,
+    inner: &Type,
+    options: &Options,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident; // The name of the newtype
+
+    // These options affect how the inner enum's variants are matched, so they belong on it
+    let enum_only_options = [
+        ("name_lookup", options.name_lookup),
+        ("ord_by_value", options.ord_by_value),
+        ("table", options.table),
+        ("exhaustive", options.exhaustive),
+        ("exhaustive_methods", options.exhaustive_methods),
+        ("total", options.total),
+        ("const", options.const_fn),
+        ("lossy_methods", options.lossy_methods),
+        ("strict", options.strict),
+        ("only", options.only.is_some()),
+        ("trace", options.trace.is_some()),
+        ("module", options.module.is_some()),
+        ("iter", options.iter),
+        ("slice_helpers", options.slice_helpers),
+        ("try_from", options.try_from),
+        ("sorted_entries", options.sorted_entries),
+        ("variants", options.variants.is_some()),
+        ("property_test", options.property_test),
+        ("from_index", options.from_index),
+        ("decode", options.decode.is_some()),
+        ("primary", options.primary),
+        ("expect", options.expect),
+        ("control_flow", options.control_flow),
+        ("contains", options.contains),
+        ("nonzero", options.nonzero),
+        ("traits", options.traits),
+        ("generic", options.generic),
+        ("result", options.result),
+        ("error", options.error.is_some()),
+        ("error_fn", options.error_fn.is_some()),
+        ("unchecked", options.unchecked),
+        ("serde", options.serde),
+        ("num_traits", options.num_traits),
+        ("num_enum", options.num_enum),
+        ("ordinals", options.ordinals),
+        ("impl_from", options.impl_from),
+        ("lossy", options.lossy),
+        ("deny_lossy", options.deny_lossy),
+        ("strict_types", options.strict_types),
+        ("values_from", options.values_from.is_some()),
+        ("strategy", options.strategy != Strategy::Match),
+        ("consts", options.consts.is_some()),
+        ("discriminants", options.discriminants),
+        ("variant_count", options.variant_count),
+        ("variant_name", options.variant_name),
+        ("name_of", options.name_of),
+        ("discriminant_range", options.discriminant_range),
+    ];
+    if let Some((option, _)) = enum_only_options.iter().find(|(_, enabled)| *enabled) {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!(
+                "the `{}` option is not supported on newtype structs, apply it to the inner enum instead",
+                option
+            ),
+        ));
+    }
+
+    let mut all_generated_methods = Vec::new();
+
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        let specified_type = &conversion_type.ty;
+        let fn_name = conversion_type.method_name("from_", "");
+        let must_use_attr = must_use_attr(options);
+
+        let mut methods = vec![quote! {
+            #must_use_attr
+            #[allow(dead_code)] // Allow this function to be unused without a warning
+            /// Converts a raw #specified_type integer value to an Option<Self> by
+            /// converting it to the inner enum and wrapping the result.
+            pub fn #fn_name(value: #specified_type) -> Option<Self> {
+                <#inner>::#fn_name(value).map(Self)
+            }
+        }];
+
+        if conversion_type.name != "bool" {
+            let to_fn_name = conversion_type.method_name("to_", "");
+            methods.push(quote! {
+                #must_use_attr
+                #[allow(dead_code)] // Allow this function to be unused without a warning
+                /// Converts the wrapped variant to a raw #specified_type integer value.
+                pub const fn #to_fn_name(&self) -> #specified_type {
+                    self.0.#to_fn_name()
+                }
+            });
+        }
+
+        if options.named {
+            let named_fn_name = conversion_type.method_name("from_", "_named");
+            methods.push(quote! {
+                #must_use_attr
+                #[allow(dead_code)] // Allow this function to be unused without a warning
+                /// Converts a raw #specified_type integer value to an Option of the
+                /// wrapped variant along with its name.
+                pub fn #named_fn_name(value: #specified_type) -> Option<(Self, &'static str)> {
+                    <#inner>::#named_fn_name(value).map(|(variant, name)| (Self(variant), name))
+                }
+            });
+        }
+
+        // Gate the methods behind the `cfg` predicate that preceded their type, if any
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+        all_generated_methods.push(quote! {
+            #( #cfg_attr #methods )*
+        });
+    }
+
+    // The derive can't emit the struct, since the compiler already does
+    let struct_def = (!options.derive).then_some(input);
+
+    Ok(quote! {
+        #struct_def // Include the original struct definition
+
+        #[automatically_derived]
+        impl #name {
+            #( #all_generated_methods )* // Expand all the generated methods
+        }
+    })
+}
+
+// A validated requested type, along with everything needed to generate its methods
+#[derive(Clone)]
+struct ConversionType {
+    // The type as written in the attribute, preserving its span, or the `core::ffi`
+    // path of a C type alias, which doesn't have to be in scope
+    ty: Type,
+    // The name of the type, e.g. `u8`
+    name: String,
+    // The span of the type, used for the generated method names
+    span: Span,
+    // Appended to the type in the generated method names, e.g. `_raw`, if `suffix = ...`
+    // was given
+    name_suffix: String,
+}
+
+impl ConversionType {
+    // Validates that the specified type is one of the supported types
+    fn new(ty: &Type, options: &Options) -> syn::Result<Self> {
+        // Extract the identifier and span from the specified type
+        let (type_ident, type_span) = match ty {
+            Type::Path(type_path) => {
+                if let Some(segment) = type_path.path.segments.last() {
+                    (segment.ident.clone(), segment.span())
+                } else {
+                    return Err(syn::Error::new_spanned(ty, "invalid type specified"));
+                }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "expected an integer type identifier (e.g., i32)",
+                ));
+            }
+        };
+
+        let type_str = type_ident.to_string();
+
+        // C type aliases are resolved through `core::ffi`, so the generated code uses
+        // whatever primitive they alias on the target, rather than the host's
+        let ty = if FFI_TYPES.contains(&type_str.as_str()) {
+            parse_quote_spanned! { type_span=> ::core::ffi::#type_ident }
+        } else if SUPPORTED_TYPES.contains(&type_str.as_str()) {
+            ty.clone()
+        } else {
+            // Validate that the specified type is one of the supported integer types
+            let mut message = format!(
+                "unsupported integer type '{}'. Supported types are {}, and the C type aliases {}.",
+                type_str,
+                SUPPORTED_TYPES.join(", "),
+                FFI_TYPES.join(", ")
+            );
+            if let Some(suggestion) = closest_type(&type_str) {
+                message.push_str(&format!(" Did you mean `{}`?", suggestion));
+            }
+            return Err(syn::Error::new_spanned(ty, message));
+        };
+
+        Ok(Self {
+            ty,
+            name: type_str,
+            span: type_span,
+            name_suffix: options
+                .suffix
+                .as_ref()
+                .map(|suffix| format!("_{}", suffix.value()))
+                .unwrap_or_default(),
+        })
+    }
+
+    // Creates a method name identifier for this type (e.g. `from_u8`) with the correct span
+    fn method_name(&self, prefix: &str, suffix: &str) -> Ident {
+        format_ident!(
+            "{}{}{}{}",
+            prefix,
+            self.name,
+            self.name_suffix,
+            suffix,
+            span = self.span
+        )
+    }
+
+    // The type that the discriminants are cast to. `bool` is a pseudo-type whose
+    // discriminants are cast to `u8`.
+    fn const_type(&self) -> TokenStream {
+        let ty = &self.ty;
+        if self.name == "bool" {
+            quote! { u8 }
+        } else {
+            quote! { #ty }
+        }
+    }
+
+    // The inclusive range of values of the type as `i128` expressions, or `None` for the
+    // `bool` pseudo-type. The range of the primitives is computed here, at macro time, so
+    // it is a pair of literals, while the range of the C type aliases depends on the target.
+    fn bounds(&self) -> Option<(TokenStream, TokenStream)> {
+        if self.name == "bool" {
+            return None;
+        }
+        match self.primitive_bounds() {
+            Some((min, max)) => Some((quote! { #min }, quote! { #max })),
+            None => {
+                let ty = &self.ty;
+                Some((
+                    quote! { (<#ty>::MIN as i128) },
+                    quote! { (<#ty>::MAX as i128) },
+                ))
+            }
+        }
+    }
+
+    // The inclusive range of values of the type, for the integer primitives, whose range
+    // is known at macro time
+    fn primitive_bounds(&self) -> Option<(i128, i128)> {
+        match self.name.as_str() {
+            "i8" => Some((i8::MIN.into(), i8::MAX.into())),
+            "u8" => Some((u8::MIN.into(), u8::MAX.into())),
+            "i16" => Some((i16::MIN.into(), i16::MAX.into())),
+            "u16" => Some((u16::MIN.into(), u16::MAX.into())),
+            "i32" => Some((i32::MIN.into(), i32::MAX.into())),
+            "u32" => Some((u32::MIN.into(), u32::MAX.into())),
+            "i64" => Some((i64::MIN.into(), i64::MAX.into())),
+            "u64" => Some((u64::MIN.into(), u64::MAX.into())),
+            "i128" => Some((i128::MIN, i128::MAX)),
+            // The values are compared as `i128`, where those above `i128::MAX` can't be told
+            // apart from negative ones, so they are treated as out of range
+            "u128" => Some((0, i128::MAX)),
+            _ => None,
+        }
+    }
+
+    // Casts a value to the type at macro time, like the generated `value as i128 as type`,
+    // or returns `None` for the types whose width depends on the target. The value of a
+    // `u128` is kept as the `i128` with the same bits.
+    fn cast_literal(&self, value: i128) -> Option<i128> {
+        let (min, max) = match self.name.as_str() {
+            "i128" | "u128" => return Some(value),
+            // The `bool` pseudo-type casts the values to `u8`
+            "bool" => (0, u8::MAX.into()),
+            _ => self.primitive_bounds()?,
+        };
+        let width = max - min + 1;
+        let wrapped = value.rem_euclid(width);
+        Some(if wrapped > max {
+            wrapped - width
+        } else {
+            wrapped
+        })
+    }
+
+    // A value cast to the type, as a literal suffixed with the type the discriminants are
+    // cast to, for the patterns and keys the macro generates. The value of a `u128` is
+    // given as the `i128` with the same bits.
+    fn literal(&self, cast: i128) -> TokenStream {
+        if self.name == "u128" {
+            let literal = Literal::u128_suffixed(cast as u128);
+            return quote! { #literal };
+        }
+        let suffix = if self.name == "bool" {
+            "u8"
+        } else {
+            &self.name
+        };
+        let magnitude = LitInt::new(
+            &format!("{}{}", cast.unsigned_abs(), suffix),
+            Span::call_site(),
+        );
+        if cast < 0 {
+            quote! { -#magnitude }
+        } else {
+            quote! { #magnitude }
+        }
+    }
+
+    // Whether the conversions from the type match a value, given along with its cast.
+    // With `strict`, only the values that the type can represent are matched, and `bool`
+    // only matches the values that become 0 or 1.
+    fn matches_literal(&self, value: i128, cast: i128, strict: bool) -> bool {
+        let (min, max) = match self.name.as_str() {
+            "bool" => (0, 1),
+            _ => self.primitive_bounds().unwrap_or((i128::MIN, i128::MAX)),
+        };
+        if strict {
+            min <= value && value <= max
+        } else {
+            cast <= max
+        }
+    }
+
+    // Whether the conversions from the type match a variant's value, given as an
+    // expression, like `matches_literal` does for the values known at macro time
+    fn matches_value(&self, raw_value: &TokenStream, strict: bool) -> TokenStream {
+        if strict {
+            self.fits(raw_value)
+        } else if self.name == "bool" {
+            quote! { (#raw_value as i128 as u8) <= 1 }
+        } else {
+            quote! { true }
+        }
+    }
+
+    // Whether a variant's value, given as an expression, is exactly representable in the
+    // type. The `bool` pseudo-type represents 0 and 1.
+    fn fits(&self, raw_value: &TokenStream) -> TokenStream {
+        let (min, max) = self.bounds().unwrap_or((quote! { 0 }, quote! { 1 }));
+        quote! { (#raw_value as i128 >= #min && #raw_value as i128 <= #max) }
+    }
+
+    // The match guard of the arm matching a value, which with the `strict` option
+    // restricts the value to types that can represent it, instead of matching it wrapped
+    fn strict_guard(&self, raw_value: &TokenStream, options: &Options) -> Option<TokenStream> {
+        options.strict.then(|| {
+            let fits = self.fits(raw_value);
+            quote! { if #fits }
+        })
+    }
+
+    // Whether the `from_*` method of this type returns `Self` rather than `Option<Self>`,
+    // which is the case when it can't fail, and for all types with `total`
+    fn returns_self(&self, options: &Context) -> bool {
+        options.total || self.never_fails(options)
+    }
+
+    // Whether the `from_*` method of this type matches every value, which is the case for
+    // the 8-bit types with `exhaustive`, and for all types with a catch-all variant
+    fn never_fails(&self, options: &Context) -> bool {
+        options.catch_all.is_some()
+            || (options.exhaustive && matches!(self.name.as_str(), "u8" | "i8"))
+    }
+
+    // Whether the `from_*` method of this type panics on the values matching no variant,
+    // which is the case with `total`, unless it can't fail in the first place
+    fn panics(&self, options: &Context) -> bool {
+        options.total && !self.never_fails(options)
+    }
+
+    // The name of the conversion from this type that reports the values matching no
+    // variant by returning `None` rather than by panicking: `from_*` itself, or the hidden
+    // `from_*_checked` generated along with a panicking one
+    fn checked_from_name(&self, options: &Context) -> Ident {
+        if self.panics(options) {
+            self.method_name("from_", "_checked")
+        } else {
+            self.method_name("from_", "")
+        }
+    }
+
+    // The expression matched against the discriminant consts. For `bool`, this is
+    // `value as u8`, so `false` matches discriminant 0 and `true` matches discriminant 1.
+    fn match_value(&self) -> TokenStream {
+        if self.name == "bool" {
+            quote! { value as u8 }
+        } else {
+            quote! { value }
+        }
+    }
+}
+
+// The names of the C and C99 fixed-width types, which aren't typos of a supported type
+// but are often written for one
+const C_TYPE_NAMES: &[(&str, &str)] = &[
+    ("char", "c_char"),
+    ("short", "c_short"),
+    ("int", "c_int"),
+    ("unsigned", "c_uint"),
+    ("long", "c_long"),
+    ("int8_t", "i8"),
+    ("uint8_t", "u8"),
+    ("int16_t", "i16"),
+    ("uint16_t", "u16"),
+    ("int32_t", "i32"),
+    ("uint32_t", "u32"),
+    ("int64_t", "i64"),
+    ("uint64_t", "u64"),
+    ("size_t", "usize"),
+    ("ssize_t", "isize"),
+    ("ptrdiff_t", "isize"),
+];
+
+// Returns the supported type closest to an unsupported one, if any is close enough to be
+// a likely typo, or the type a C type name corresponds to. Among the types at the same
+// edit distance, the one sharing the longest prefix wins, and then the one closest in
+// length.
+fn closest_type(type_str: &str) -> Option<&'static str> {
+    if let Some((_, mapped)) = C_TYPE_NAMES.iter().find(|(name, _)| *name == type_str) {
+        return Some(mapped);
+    }
+    SUPPORTED_TYPES
+        .iter()
+        .chain(FFI_TYPES)
+        .map(|candidate| (edit_distance(type_str, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, candidate)| {
+            let shared_prefix = type_str
+                .chars()
+                .zip(candidate.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (
+                *distance,
+                std::cmp::Reverse(shared_prefix),
+                type_str.len().abs_diff(candidate.len()),
+            )
+        })
+        .map(|(_, candidate)| candidate)
+}
+
+// The edit distance between two strings: the number of single-character insertions,
+// deletions, substitutions and swaps of adjacent characters turning one into the other
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // `distances[i][j]` is the distance from the first `i` characters of `a` to the
+    // first `j` characters of `b`
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = substitution
+                .min(distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+// Returns the requested type with the widest range, whose conversions the narrower types
+// may call instead of matching the values themselves, for enums with enough variants that
+// matching them once per type slows compilation down. The type must be a primitive that
+// isn't gated behind a `cfg`.
+fn shared_match_type(
+    variants: &[&Variant],
+    options: &Options,
+) -> syn::Result<Option<ConversionType>> {
+    if variants.len() < SHARED_MATCH_MIN_VARIANTS {
+        return Ok(None);
+    }
+    let mut widest: Option<(ConversionType, u128)> = None;
+    for target in options.types.iter().filter(|target| target.cfg.is_none()) {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        let Some((min, max)) = conversion_type.primitive_bounds() else {
+            continue;
+        };
+        let span = max.abs_diff(min);
+        if widest
+            .as_ref()
+            .is_none_or(|(_, widest_span)| span > *widest_span)
+        {
+            widest = Some((conversion_type, span));
+        }
+    }
+    Ok(widest.map(|(conversion_type, _)| conversion_type))
+}
+
+// Whether the conversions from a type can call those from the shared type, which is the
+// case when the shared type holds every value of the type, and both match the same
+// variants for each of them: with `strict`, or when every value fits in the type, so that
+// neither cast wraps. The conversions that differ from the shared type's in more than the
+// type of the value keep their own match: the table, the exhaustive conversions, and those
+// passing the value to a trace hook.
+fn shares_match(
+    conversion_type: &ConversionType,
+    target: &TargetType,
+    shared_type: &ConversionType,
+    variants: &[&Variant],
+    options: &Context,
+) -> bool {
+    let eight_bit = matches!(conversion_type.name.as_str(), "u8" | "i8");
+    if conversion_type.name == shared_type.name
+        // The `u128` values above `i128::MAX` would be cast to negative values
+        || conversion_type.name == "u128"
+        || target.cfg.is_some()
+        || options.trace.is_some()
+        // The hidden `from_*_checked` of the panicking conversions has its own match
+        || options.total
+        || options.lossy_methods
+        || (options.table && conversion_type.name == "u8")
+        || ((options.exhaustive || options.exhaustive_methods) && eight_bit)
+    {
+        return false;
+    }
+    let (Some((min, max)), Some((shared_min, shared_max))) = (
+        conversion_type.primitive_bounds(),
+        shared_type.primitive_bounds(),
+    ) else {
+        return false;
+    };
+    if min < shared_min || max > shared_max {
+        return false;
+    }
+    options.strict
+        || variants.iter().all(|variant| {
+            literal_values(variant, options).is_some_and(|(value, aliases)| {
+                std::iter::once(value)
+                    .chain(aliases)
+                    .all(|value| min <= value && value <= max)
+            })
+        })
+}
+
+// Generates all methods for a single requested integer type. The conversions from the
+// type only match the convertible variants, while the conversions to it cover all variants.
+fn generate_type_methods(
+    name: &Ident,
+    all_variants: &[&Variant],
+    variants: &[&Variant],
+    target: &TargetType,
+    shared_type: Option<&ConversionType>,
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let conversion_type = ConversionType::new(&target.ty, options)?;
+
+    // The conversions from the type call those from the shared type instead of matching
+    let shared_type = shared_type
+        .filter(|shared| shares_match(&conversion_type, target, shared, variants, options));
+
+    // The discriminant consts are declared once per type, as associated consts, and
+    // shared by all methods matching against them
+    let (mut methods, match_arms) = match shared_type {
+        Some(_) => (Vec::new(), Vec::new()),
+        None => generate_discriminant_consts(variants, &conversion_type, options),
+    };
+
+    methods.push(generate_from_method(
+        name,
+        variants,
+        &match_arms,
+        &conversion_type,
+        shared_type,
+        options,
+    ));
+
+    // With `total`, `from_*` panics on the values matching no variant, so the helpers
+    // reporting them call a hidden `from_*_checked` returning `None` instead
+    if conversion_type.panics(options) {
+        let checked_type = ConversionType {
+            name_suffix: format!("{}_checked", conversion_type.name_suffix),
+            ..conversion_type.clone()
+        };
+        let checked_method = generate_from_method(
+            name,
+            variants,
+            &match_arms,
+            &checked_type,
+            None,
+            &Context {
+                options: Options {
+                    total: false,
+                    ..options.options.clone()
+                },
+                ..options.clone()
+            },
+        );
+        methods.push(quote! {
+            #[doc(hidden)]
+            #checked_method
+        });
+    }
+
+    // With the `lossy_methods` option, `from_*` is strict, while `from_*_lossy` keeps
+    // matching the values cast with wrapping. The arms are the ones `from_*` would have
+    // without `strict`, which match the consts already declared, without the guards.
+    if options.lossy_methods {
+        let lossy_options = Context {
+            options: Options {
+                strict: false,
+                ..options.options.clone()
+            },
+            ..options.clone()
+        };
+        let (_, lossy_arms) =
+            generate_discriminant_consts(variants, &conversion_type, &lossy_options);
+        let lossy_type = ConversionType {
+            name_suffix: format!("{}_lossy", conversion_type.name_suffix),
+            ..conversion_type.clone()
+        };
+        methods.push(generate_from_method(
+            name,
+            variants,
+            &lossy_arms,
+            &lossy_type,
+            None,
+            &lossy_options,
+        ));
+    }
+
+    // With the `exhaustive_methods` option, the 8-bit types additionally get an infallible
+    // `from_*_exhaustive`, generated like `from_*` with the `exhaustive` option, without
+    // the table and the catch-all variant, whose bodies can fail
+    if options.exhaustive_methods && matches!(conversion_type.name.as_str(), "u8" | "i8") {
+        let exhaustive_type = ConversionType {
+            name_suffix: format!("{}_exhaustive", conversion_type.name_suffix),
+            ..conversion_type.clone()
+        };
+        let exhaustive_options = Context {
+            options: Options {
+                exhaustive: true,
+                table: false,
+                ..options.options.clone()
+            },
+            catch_all: None,
+            ..options.clone()
+        };
+        methods.push(generate_from_method(
+            name,
+            variants,
+            &match_arms,
+            &exhaustive_type,
+            None,
+            &exhaustive_options,
+        ));
+    }
+
+    methods.extend(generate_to_method(all_variants, &conversion_type, options));
+
+    if let Some(scheme) = &options.consts {
+        methods.extend(generate_value_consts(
+            all_variants,
+            &conversion_type,
+            scheme,
+            options,
+        ));
+    }
+
+    if options.named {
+        methods.push(generate_from_named_method(
+            &match_arms,
+            &conversion_type,
+            shared_type,
+            options,
+        ));
+    }
+
+    if options.name_of {
+        methods.push(generate_name_of_method(
+            &match_arms,
+            &conversion_type,
+            shared_type,
+            options,
+        ));
+    }
+
+    if options.assert_distinct {
+        methods.push(generate_assert_distinct_method(
+            name,
+            variants,
+            &conversion_type,
+            options,
+        ));
+    }
+
+    if options.slice_helpers {
+        methods.extend(generate_slice_helpers(
+            all_variants,
+            &conversion_type,
+            options,
+        ));
+    }
+
+    if options.opt_helpers {
+        methods.push(generate_from_opt_method(&conversion_type, options));
+    }
+
+    if options.nonzero {
+        methods.extend(generate_from_nonzero_method(&conversion_type, options));
+    }
+
+    if options.result {
+        methods.push(generate_try_from_method(name, &conversion_type, options));
+    }
+
+    if options
+        .unchecked_repr
+        .as_ref()
+        .is_some_and(|repr| *repr == conversion_type.name)
+    {
+        methods.push(generate_from_unchecked_method(&conversion_type, options));
+    }
+
+    if options.contains {
+        methods.push(generate_contains_method(
+            &match_arms,
+            &conversion_type,
+            shared_type,
+            options,
+        ));
+    }
+
+    if options.expect {
+        methods.push(generate_expect_method(name, &conversion_type, options));
+    }
+
+    if options.control_flow {
+        methods.push(generate_from_flow_method(&conversion_type, options));
+    }
+
+    if let Some(decode) = &options.decode {
+        methods.extend(generate_decode_method(decode, &conversion_type, options));
+    }
+
+    if options.discriminants {
+        methods.extend(generate_discriminants_const(
+            all_variants,
+            &conversion_type,
+            options,
+        ));
+    }
+
+    // A discriminant can't be cast to `bool`
+    if options.discriminant_range && conversion_type.name != "bool" {
+        let specified_type = &conversion_type.ty;
+        let values = all_variants
+            .iter()
+            .map(|variant| {
+                let raw_value = raw_value(quote! { Self }, variant, options);
+                quote! { #raw_value as i128 as #specified_type }
+            })
+            .collect();
+        methods.extend(generate_discriminant_range(
+            values,
+            specified_type,
+            &format!(
+                "_{}{}",
+                conversion_type.name.to_uppercase(),
+                conversion_type.name_suffix.to_uppercase()
+            ),
+        ));
+    }
+
+    if options.sorted_entries {
+        methods.extend(generate_sorted_entries_method(
+            name,
+            all_variants,
+            &conversion_type,
+            options,
+        ));
+    }
+
+    if options.saturating {
+        methods.extend(generate_to_saturating_method(
+            all_variants,
+            &conversion_type,
+            options,
+        ));
+    }
+
+    // Gate the methods behind the `cfg` predicate that preceded their type, if any
+    let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+    Ok(quote! {
+        #( #cfg_attr #methods )*
+    })
+}
+
+// A value matched by the conversions to a type: the pattern matching it, cast to the type,
+// along with the variant it converts to and the match guard of its arm. The pattern is
+// either a literal or a range, or the associated const holding the value.
+#[derive(Clone)]
+struct MatchArm<'a> {
+    variant: &'a Variant,
+    pattern: TokenStream,
+    guard: Option<TokenStream>,
+    // The variant's `cfg` attributes, which the const and the arm carry
+    cfgs: TokenStream,
+}
+
+// Generates an associated `const` declaration for each variant, holding its discriminant
+// cast to the given type, and for each alias of a variant, holding the alias cast to the
+// type. Returns the declarations along with the match arms using the consts, with the
+// aliases after all of the variants' own values.
+// Declaring them once per type, rather than in every method, keeps the expansion small
+// for enums with many variants and types. When the macro can evaluate every value, the
+// arms match literals instead, and no consts are declared.
+fn generate_discriminant_consts<'a>(
+    variants: &[&'a Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> (Vec<TokenStream>, Vec<MatchArm<'a>>) {
+    if let Some(match_arms) = literal_match_arms(variants, conversion_type, options) {
+        return (Vec::new(), match_arms);
+    }
+
+    let const_type = conversion_type.const_type();
+    let type_name = conversion_type.name.to_uppercase();
+
+    // Each value is the variant's own value, followed by its aliases
+    let values = variants
+        .iter()
+        .map(|variant| {
+            let const_name = format_ident!(
+                "__RAWENUM_DISCRIMINANT_{}_{}",
+                variant_str(variant).to_uppercase(),
+                type_name,
+                span = variant.ident.span()
+            );
+            (
+                *variant,
+                const_name,
+                raw_value(quote! { Self }, variant, options),
+            )
+        })
+        .chain(variants.iter().flat_map(|variant| {
+            alias_values(variant)
+                .into_iter()
+                .enumerate()
+                .map(|(index, (alias, _))| {
+                    let const_name = format_ident!(
+                        "__RAWENUM_ALIAS_{}_{}_{}",
+                        variant_str(variant).to_uppercase(),
+                        index,
+                        type_name,
+                        span = variant.ident.span()
+                    );
+                    (*variant, const_name, alias.to_token_stream())
+                })
+                .collect::<Vec<_>>()
+        }));
+
+    values
+        .map(|(variant, const_name, raw_value)| {
+            // Generate the const declaration:
+            // `const __RAWENUM_DISCRIMINANT_VARIANT_TYPE: TargetType = Self::VariantName as i128 as TargetType;`
+            // Going through `i128`, which holds every discriminant value, makes the cast a plain
+            // two's-complement narrowing of the full-width value regardless of the enum's repr.
+            let cfgs = variant_cfgs(variant);
+            let declaration = quote! {
+                #cfgs
+                #[doc(hidden)]
+                const #const_name: #const_type = #raw_value as i128 as #const_type;
+            };
+            let guard = conversion_type.strict_guard(&raw_value, options);
+
+            (
+                declaration,
+                MatchArm {
+                    variant,
+                    pattern: quote! { Self::#const_name },
+                    guard,
+                    cfgs,
+                },
+            )
+        })
+        .unzip()
+}
+
+// Returns the match arms with literal patterns, when the macro can evaluate every value
+// and cast it to the type. The values the conversions don't match, and those claimed by
+// an earlier variant, which the match would never reach, are left out. The values of a
+// variant that follow each other, such as a run of aliases, are matched by a range.
+// Returns `None` when a variant would be left without an arm, since the conversions
+// would then never construct it, and the compiler would warn about it.
+fn literal_match_arms<'a>(
+    variants: &[&'a Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<Vec<MatchArm<'a>>> {
+    let mut values = Vec::new();
+    let mut aliases = Vec::new();
+    for (index, variant) in variants.iter().enumerate() {
+        let (value, variant_aliases) = literal_values(variant, options)?;
+        values.push((index, value));
+        aliases.extend(variant_aliases.into_iter().map(|alias| (index, alias)));
+    }
+
+    // The values matched by each variant, cast to the type
+    let mut claimed = BTreeSet::new();
+    let mut matched = vec![Vec::new(); variants.len()];
+    for (index, value) in values.into_iter().chain(aliases) {
+        let cast = conversion_type.cast_literal(value)?;
+        if conversion_type.matches_literal(value, cast, options.strict) && claimed.insert(cast) {
+            matched[index].push(cast);
+        }
+    }
+    if matched.iter().any(Vec::is_empty) {
+        return None;
+    }
+
+    let mut match_arms = Vec::new();
+    for (variant, mut casts) in variants.iter().zip(matched) {
+        casts.sort_unstable();
+        let mut runs: Vec<(i128, i128)> = Vec::new();
+        for cast in casts {
+            match runs.last_mut() {
+                // The `u128` values above `i128::MAX` are negative here, so they are sorted
+                // before the others, and are never merged into a range
+                Some((_, last)) if conversion_type.name != "u128" && *last + 1 == cast => {
+                    *last = cast;
+                }
+                _ => runs.push((cast, cast)),
+            }
+        }
+        match_arms.extend(runs.into_iter().map(|(first, last)| {
+            let first_literal = conversion_type.literal(first);
+            let pattern = if first == last {
+                first_literal
+            } else {
+                let last_literal = conversion_type.literal(last);
+                quote! { #first_literal..=#last_literal }
+            };
+            MatchArm {
+                variant,
+                pattern,
+                guard: None,
+                cfgs: TokenStream::new(),
+            }
+        }));
+    }
+    Some(match_arms)
+}
+
+// Generates the `from_*` method for a single requested integer type
+fn generate_from_method(
+    name: &Ident,
+    variants: &[&Variant],
+    match_arms: &[MatchArm],
+    conversion_type: &ConversionType,
+    shared_type: Option<&ConversionType>,
+    options: &Context,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let type_str = conversion_type.name.as_str();
+    let fn_name = conversion_type.method_name("from_", "");
+    let match_value = conversion_type.match_value();
+
+    // With the `exhaustive` option, the 8-bit conversions return `Self` and have no
+    // catch-all arm. The compiler's exhaustiveness check on the match then proves that
+    // the cast discriminants cover every value of the type.
+    let exhaustive = options.exhaustive && matches!(type_str, "u8" | "i8");
+    // With the `total` option, the conversions return `Self` and panic on invalid values
+    let total = options.total && !exhaustive;
+    // With a catch-all variant, the conversions return `Self` and fall back to it
+    let catch_all = options.catch_all.as_ref().filter(|_| !exhaustive);
+
+    // Generate the match arm using the pattern: `Self::CONST_NAME_TYPE => Some(Self::VariantName),`
+    let mut local_match_arms: Vec<TokenStream> = match_arms
+        .iter()
+        .map(|arm| {
+            let MatchArm {
+                variant,
+                pattern,
+                guard,
+                cfgs,
+            } = arm;
+            let variant_name = &variant.ident;
+            if exhaustive || total || catch_all.is_some() {
+                quote! { #cfgs #pattern #guard => Self::#variant_name, }
+            } else {
+                quote! { #cfgs #pattern #guard => Some(Self::#variant_name), }
+            }
+        })
+        .collect();
+
+    // The result for any value that doesn't match any discriminant (within the range of
+    // the target type after casting the discriminant). Let the trace hook (if any) observe
+    // the unmatched value first.
+    let trace_call = options
+        .trace
+        .as_ref()
+        .map(|trace| quote! { #trace(value); });
+    let fallback = if total {
+        quote! { panic!("invalid discriminant {}", value) }
+    } else if let Some(catch_all) = catch_all {
+        match &options.catch_all_payload {
+            Some(payload) => quote! { Self::#catch_all(value as #payload) },
+            None => quote! { Self::#catch_all },
+        }
+    } else {
+        quote! { None }
+    };
+
+    // Add the catch-all arm for the unmatched values
+    if !exhaustive {
+        local_match_arms.push(quote! {
+            _ => {
+                #trace_call
+                #fallback
+            }
+        });
+    }
+
+    // With the `phf` strategy, the values are looked up in a perfect hash map, for the
+    // types whose width the macro knows, so that it can cast the values to them
+    let phf_entries = (cfg!(feature = "phf") && options.strategy == Strategy::Phf && !exhaustive)
+        .then(|| generate_phf_entries(variants, conversion_type, options))
+        .flatten();
+
+    // A contiguous run of discriminants is matched by a range check, when the type can
+    // represent the whole run without wrapping. The exhaustive conversions keep the match,
+    // whose exhaustiveness check is what proves the coverage.
+    let contiguous_run = options
+        .contiguous_run
+        .as_ref()
+        .filter(|_| !exhaustive)
+        .filter(|(_, first, last)| {
+            conversion_type
+                .primitive_bounds()
+                .is_some_and(|(min, max)| min <= *first && *last <= max)
+        });
+
+    let body = if let Some(shared_type) = shared_type {
+        let shared_fn_name = shared_type.method_name("from_", "");
+        let shared_ty = &shared_type.ty;
+        quote! {
+            // The shared type holds every value of this one, which matches the same variants
+            Self::#shared_fn_name(value as #shared_ty)
+        }
+    } else if options.table && type_str == "u8" {
+        generate_table_body(name, variants, options)
+    } else if let Some(entries) = phf_entries {
+        let (keys, indices): (Vec<_>, Vec<_>) = entries.into_iter().unzip();
+        let index_arms = variants.iter().enumerate().map(|(index, variant)| {
+            let variant_name = &variant.ident;
+            if total || catch_all.is_some() {
+                quote! { Some(#index) => Self::#variant_name, }
+            } else {
+                quote! { Some(#index) => Some(Self::#variant_name), }
+            }
+        });
+        quote! {
+            static VALUE_MAP: ::phf::Map<#specified_type, usize> = ::phf::phf_map! {
+                #( #keys => #indices, )*
+            };
+            match VALUE_MAP.get(&value).copied() {
+                #( #index_arms )*
+                _ => {
+                    #trace_call
+                    #fallback
+                }
+            }
+        }
+    } else if options.strategy == Strategy::BinarySearch && !exhaustive {
+        let search = generate_binary_search(name, variants, conversion_type, options);
+        let index_arms = variants.iter().enumerate().map(|(index, variant)| {
+            let variant_name = &variant.ident;
+            if total || catch_all.is_some() {
+                quote! { Some(#index) => Self::#variant_name, }
+            } else {
+                quote! { Some(#index) => Some(Self::#variant_name), }
+            }
+        });
+        quote! {
+            #search
+            match index {
+                #( #index_arms )*
+                _ => {
+                    #trace_call
+                    #fallback
+                }
+            }
+        }
+    } else if let Some((repr, first, last)) = contiguous_run {
+        let matched = if total || catch_all.is_some() {
+            quote! { variant }
+        } else {
+            quote! { Some(variant) }
+        };
+        quote! {
+            // The discriminants form a contiguous run, so any value within it is one
+            if value as i128 >= #first && value as i128 <= #last {
+                // SAFETY: the enum is represented as #repr, which holds every value of the
+                // run, and each of them is the discriminant of a variant
+                let variant = unsafe { ::core::mem::transmute::<#repr, Self>(value as #repr) };
+                #matched
+            } else {
+                #trace_call
+                #fallback
+            }
+        }
+    } else {
+        quote! {
+            // Match the input value directly against the constants of the same type.
+            match #match_value {
+                // Expand all the collected local match arms
+                #( #local_match_arms )*
+            }
+        }
+    };
+
+    // Note deprecated variants in the docs of the generated method. Using a deprecated
+    // variant in the associated discriminant consts already triggers the `deprecated` lint.
+    let deprecation_docs: Vec<String> = variants
+        .iter()
+        .filter_map(|variant| {
+            let attr = variant
+                .attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("deprecated"))?;
+            Some(match deprecation_note(attr) {
+                Some(note) => format!(" - `{}` is deprecated: {}", variant.ident, note),
+                None => format!(" - `{}` is deprecated.", variant.ident),
+            })
+        })
+        .collect();
+    let deprecation_header = (!deprecation_docs.is_empty()).then(|| {
+        quote! {
+            ///
+            /// # Deprecated variants
+            ///
+        }
+    });
+
+    let must_use_attr = must_use_attr(options);
+
+    // Inlining only pays off for small matches, while inlining huge ones bloats call sites
+    let inline_attr = options
+        .auto_inline
+        .filter(|&threshold| variants.len() < threshold)
+        .map(|_| quote! { #[inline] });
+
+    // Point the panic of a total conversion at the caller
+    let track_caller_attr = total.then(|| quote! { #[track_caller] });
+    // The match over the discriminant consts is const-compatible
+    let const_token = options.const_fn.then(|| quote! { const });
+
+    let (return_type, docs) = if exhaustive {
+        (
+            quote! { Self },
+            quote! {
+                /// Converts a raw #specified_type integer value to the variant with the
+                /// matching discriminant (when cast to #specified_type).
+                ///
+                /// The discriminants cover every possible value, so this never fails.
+            },
+        )
+    } else if let Some(catch_all) = catch_all {
+        let catch_all_doc = if options.catch_all_payload.is_some() {
+            format!(
+                " Values that don't match the discriminant of any variant convert to `{}`,\n holding the value.",
+                catch_all.unraw()
+            )
+        } else {
+            format!(
+                " Values that don't match the discriminant of any variant convert to `{}`.",
+                catch_all.unraw()
+            )
+        };
+        (
+            quote! { Self },
+            quote! {
+                /// Converts a raw #specified_type integer value to the variant with the
+                /// matching discriminant (when cast to #specified_type).
+                ///
+                #[doc = #catch_all_doc]
+            },
+        )
+    } else if total {
+        (
+            quote! { Self },
+            quote! {
+                /// Converts a raw #specified_type integer value to the variant with the
+                /// matching discriminant (when cast to #specified_type).
+                ///
+                /// # Panics
+                ///
+                /// Panics if the value doesn't match the discriminant of any variant.
+            },
+        )
+    } else {
+        (
+            quote! { Option<Self> },
+            quote! {
+                /// Converts a raw #specified_type integer value to an Option<Self>.
+                ///
+                /// Returns `Some(variant)` if the value matches the discriminant
+                /// (when cast to #specified_type) of a variant. Returns `None` otherwise.
+            },
+        )
+    };
+
+    // Generate the code for a single `from_*` function
+    quote! {
+        #must_use_attr
+        #inline_attr
+        #track_caller_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        #docs
+        #deprecation_header
+        #( #[doc = #deprecation_docs] )*
+        pub #const_token fn #fn_name(value: #specified_type) -> #return_type {
+            #body
+        }
+    }
+}
+
+// Generates the `from_*_named` method, which also returns the name of the matched variant
+fn generate_from_named_method(
+    match_arms: &[MatchArm],
+    conversion_type: &ConversionType,
+    shared_type: Option<&ConversionType>,
+    options: &Options,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("from_", "_named");
+    let match_value = conversion_type.match_value();
+    let must_use_attr = must_use_attr(options);
+
+    let local_match_arms = match_arms.iter().map(|arm| {
+        let MatchArm {
+            variant,
+            pattern,
+            guard,
+            cfgs,
+        } = arm;
+        let variant_name = &variant.ident;
+        let variant_str = variant_str(variant);
+        quote! {
+            #cfgs
+            #pattern #guard => Some((Self::#variant_name, #variant_str)),
+        }
+    });
+    let body = match shared_type {
+        Some(shared_type) => {
+            let shared_fn_name = shared_type.method_name("from_", "_named");
+            let shared_ty = &shared_type.ty;
+            quote! { Self::#shared_fn_name(value as #shared_ty) }
+        }
+        None => quote! {
+            match #match_value {
+                #( #local_match_arms )*
+                _ => None,
+            }
+        },
+    };
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a raw #specified_type integer value to an Option of the matching
+        /// variant along with its name.
+        ///
+        /// Returns `Some((variant, name))` if the value matches the discriminant
+        /// (when cast to #specified_type) of a variant. Returns `None` otherwise.
+        pub fn #fn_name(value: #specified_type) -> Option<(Self, &'static str)> {
+            #body
+        }
+    }
+}
+
+// Generates the `name_of_*` method, which returns the name of the variant matching a value
+// without constructing it
+fn generate_name_of_method(
+    match_arms: &[MatchArm],
+    conversion_type: &ConversionType,
+    shared_type: Option<&ConversionType>,
+    options: &Options,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("name_of_", "");
+    let match_value = conversion_type.match_value();
+    let must_use_attr = must_use_attr(options);
+
+    let local_match_arms = match_arms.iter().map(|arm| {
+        let MatchArm {
+            variant,
+            pattern,
+            guard,
+            cfgs,
+        } = arm;
+        let variant_str = variant_str(variant);
+        quote! {
+            #cfgs
+            #pattern #guard => Some(#variant_str),
+        }
+    });
+    let body = match shared_type {
+        Some(shared_type) => {
+            let shared_fn_name = shared_type.method_name("name_of_", "");
+            let shared_ty = &shared_type.ty;
+            quote! { Self::#shared_fn_name(value as #shared_ty) }
+        }
+        None => quote! {
+            // The catch-all arm is unreachable when the discriminants cover every value
+            #[allow(unreachable_patterns)]
+            match #match_value {
+                #( #local_match_arms )*
+                _ => None,
+            }
+        },
+    };
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns the name of the variant matching a raw #specified_type integer
+        /// value, without constructing the variant.
+        ///
+        /// Returns `None` if the value doesn't match the discriminant (when cast to
+        /// #specified_type) of any variant.
+        pub const fn #fn_name(value: #specified_type) -> Option<&'static str> {
+            #body
+        }
+    }
+}
+
+// Generates the `from_opt_*` method, which converts an optional raw value, for chaining
+// without a closure
+fn generate_from_opt_method(conversion_type: &ConversionType, options: &Context) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let from_fn_name = conversion_type.method_name("from_", "");
+    let fn_name = conversion_type.method_name("from_opt_", "");
+    let must_use_attr = must_use_attr(options);
+
+    // Exhaustive and total conversions return `Self`, so they map rather than chain
+    let body = if conversion_type.returns_self(options) {
+        quote! { value.map(Self::#from_fn_name) }
+    } else {
+        quote! { value.and_then(Self::#from_fn_name) }
+    };
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts an optional raw #specified_type integer value to an Option<Self>.
+        ///
+        /// Returns `None` if `value` is `None`, or if it doesn't match the discriminant
+        /// (when cast to #specified_type) of any variant.
+        pub fn #fn_name(value: Option<#specified_type>) -> Option<Self> {
+            #body
+        }
+    }
+}
+
+// Generates the `try_from_*` method, which returns the value that matches no variant in
+// an `<Enum>InvalidRawValue` error, rather than discarding it like the `from_*` method
+fn generate_try_from_method(
+    name: &Ident,
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let from_fn_name = conversion_type.checked_from_name(options);
+    let fn_name = conversion_type.method_name("try_from_", "");
+    let error_name = format_ident!("{}InvalidRawValue", name.unraw());
+    let must_use_attr = must_use_attr(options);
+    // A match rather than `ok_or`, so this can be a `const fn` whenever `from_*` is
+    let const_token = options.const_fn.then(|| quote! { const });
+
+    // A custom error is constructed from the generated one, with `error_fn` or `From`
+    let (error_type, error) = match (&options.error, &options.error_fn) {
+        (Some(error_type), Some(error_fn)) => (
+            quote! { #error_type },
+            quote! { #error_fn(#error_name { value }) },
+        ),
+        (Some(error_type), None) => (
+            quote! { #error_type },
+            quote! { <#error_type as ::core::convert::From<_>>::from(#error_name { value }) },
+        ),
+        _ => (
+            quote! { #error_name<#specified_type> },
+            quote! { #error_name { value } },
+        ),
+    };
+
+    // Conversions matching every value never fail
+    let body = if conversion_type.never_fails(options) {
+        quote! { Ok(Self::#from_fn_name(value)) }
+    } else {
+        quote! {
+            match Self::#from_fn_name(value) {
+                Some(variant) => Ok(variant),
+                None => Err(#error),
+            }
+        }
+    };
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a raw #specified_type integer value to the variant with the matching
+        /// discriminant (when cast to #specified_type).
+        ///
+        /// Returns an error holding `value` if it doesn't match any variant.
+        pub #const_token fn #fn_name(value: #specified_type) -> Result<Self, #error_type> {
+            #body
+        }
+    }
+}
+
+// Generates the `<Enum>InvalidRawValue` error type returned by the `try_from_*` methods,
+// which holds the value that matched no variant. Its message lists the values that are
+// accepted instead, so it is implemented for each type.
+fn generate_invalid_raw_value(
+    input: &DeriveInput,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let error_name = format_ident!("{}InvalidRawValue", name.unraw());
+    let error_doc = format!(
+        " The error returned when a raw integer value matches no variant of [`{}`].",
+        name.unraw()
+    );
+    let message = format!("invalid raw value {{}} for `{}`", name.unraw());
+
+    let mut display_impls = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        let specified_type = &conversion_type.ty;
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+        // Each accepted value is written by its own statement, so it can carry the cfg of
+        // its variant. The values are cast like the consts matched by `from_*`.
+        let values = variants.iter().flat_map(|variant| {
+            let cfgs = variant_cfgs(variant);
+            matched_values(name, variant, options)
+                .into_iter()
+                .map(move |value| (cfgs.clone(), value))
+        });
+        let accepted_values = values.map(|(cfgs, value)| {
+            let (accepted, cast_value) = if conversion_type.name == "bool" {
+                (
+                    quote! { (#value as i128 as u8) <= 1 },
+                    quote! { (#value as i128 as u8) == 1 },
+                )
+            } else if options.strict {
+                (
+                    conversion_type.fits(&value),
+                    quote! { #value as i128 as #specified_type },
+                )
+            } else {
+                (
+                    quote! { true },
+                    quote! { #value as i128 as #specified_type },
+                )
+            };
+            quote! {
+                #cfgs
+                if #accepted {
+                    write!(f, "{}{}", separator, #cast_value)?;
+                    separator = ", ";
+                }
+            }
+        });
+
+        display_impls.push(quote! {
+            #cfg_attr
+            #[automatically_derived]
+            impl ::core::fmt::Display for #error_name<#specified_type> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, #message, self.value)?;
+                    let mut separator = ", expected one of ";
+                    #( #accepted_values )*
+                    // Without any accepted values, nothing else is written
+                    let _ = separator;
+                    Ok(())
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(dead_code)]
+        #vis struct #error_name<T> {
+            /// The value that matched no variant.
+            pub value: T,
+        }
+
+        #( #display_impls )*
+
+        #[automatically_derived]
+        impl<T: ::core::fmt::Debug> ::core::error::Error for #error_name<T> where Self: ::core::fmt::Display {}
+    })
+}
+
+// Generates the `from_nonzero_*` method, converting a `NonZero` raw value, for callers
+// that already hold niche-optimized values. `bool` has no `NonZero` counterpart.
+fn generate_from_nonzero_method(
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<TokenStream> {
+    if conversion_type.name == "bool" {
+        return None;
+    }
+    let specified_type = &conversion_type.ty;
+    let from_fn_name = conversion_type.method_name("from_", "");
+    let fn_name = conversion_type.method_name("from_nonzero_", "");
+    let must_use_attr = must_use_attr(options);
+    // `NonZero::get` is a `const fn`, so this can be one whenever `from_*` is
+    let const_token = options.const_fn.then(|| quote! { const });
+    let return_type = if conversion_type.returns_self(options) {
+        quote! { Self }
+    } else {
+        quote! { Option<Self> }
+    };
+
+    Some(quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a non-zero raw #specified_type integer value like the `from_*`
+        /// method of the type, without having to call `get` first.
+        pub #const_token fn #fn_name(value: ::core::num::NonZero<#specified_type>) -> #return_type {
+            Self::#from_fn_name(value.get())
+        }
+    })
+}
+
+// Generates the `from_*_unchecked` method for the type that the enum is represented as,
+// which transmutes the raw value into the enum without matching it
+fn generate_from_unchecked_method(
+    conversion_type: &ConversionType,
+    options: &Options,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("from_", "_unchecked");
+    let must_use_attr = must_use_attr(options);
+    let const_token = options.const_fn.then(|| quote! { const });
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a raw #specified_type integer value to the variant with that
+        /// discriminant, without checking that there is one.
+        ///
+        /// # Safety
+        ///
+        /// `value` must be the discriminant of a variant. `wire` values and aliases
+        /// aren't discriminants, so they must not be passed.
+        pub #const_token unsafe fn #fn_name(value: #specified_type) -> Self {
+            // SAFETY: the enum is represented as #specified_type, and the caller
+            // guarantees that `value` is the discriminant of a variant
+            unsafe { ::core::mem::transmute::<#specified_type, Self>(value) }
+        }
+    }
+}
+
+// Generates the `contains_*` method, a `const fn` checking whether a raw value matches
+// any variant, for match guards and const contexts
+fn generate_contains_method(
+    match_arms: &[MatchArm],
+    conversion_type: &ConversionType,
+    shared_type: Option<&ConversionType>,
+    options: &Options,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("contains_", "");
+    let match_value = conversion_type.match_value();
+    let must_use_attr = must_use_attr(options);
+    let patterns = match_arms.iter().map(|arm| &arm.pattern);
+    let guards = match_arms.iter().map(|arm| &arm.guard);
+    let cfgs = match_arms.iter().map(|arm| &arm.cfgs);
+
+    let body = match shared_type {
+        Some(shared_type) => {
+            let shared_fn_name = shared_type.method_name("contains_", "");
+            let shared_ty = &shared_type.ty;
+            quote! { Self::#shared_fn_name(value as #shared_ty) }
+        }
+        None => quote! {
+            // The catch-all arm is unreachable when the discriminants cover every value
+            #[allow(unreachable_patterns)]
+            match #match_value {
+                #( #cfgs #patterns #guards => true, )*
+                _ => false,
+            }
+        },
+    };
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns whether a raw #specified_type integer value matches the discriminant
+        /// (when cast to #specified_type) of any variant.
+        ///
+        /// This is a `const fn`, so it can be used in match guards and const contexts.
+        pub const fn #fn_name(value: #specified_type) -> bool {
+            #body
+        }
+    }
+}
+
+// Generates the `expect_*` method, which converts a raw value and panics if it doesn't
+// match any variant. It is `#[track_caller]`, so the panic points at the caller.
+fn generate_expect_method(
+    name: &Ident,
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let from_fn_name = conversion_type.method_name("from_", "");
+    let fn_name = conversion_type.method_name("expect_", "");
+    let must_use_attr = must_use_attr(options);
+
+    // Exhaustive conversions can't fail, and total ones already panic
+    let body = if conversion_type.returns_self(options) {
+        quote! { Self::#from_fn_name(value) }
+    } else {
+        let message = format!(
+            "invalid raw {} value for `{}`: {{}}",
+            conversion_type.name,
+            name.unraw()
+        );
+        quote! {
+            match Self::#from_fn_name(value) {
+                Some(variant) => variant,
+                None => panic!(#message, value),
+            }
+        }
+    };
+
+    quote! {
+        #must_use_attr
+        #[track_caller]
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a raw #specified_type integer value to the variant with the
+        /// matching discriminant (when cast to #specified_type).
+        ///
+        /// # Panics
+        ///
+        /// Panics with the value if it doesn't match the discriminant of any variant.
+        pub fn #fn_name(value: #specified_type) -> Self {
+            #body
+        }
+    }
+}
+
+// Generates the `from_*_flow` method, which returns `ControlFlow` for parser loops:
+// `Continue` with the matched variant, or `Break` with the unmatched value
+fn generate_from_flow_method(conversion_type: &ConversionType, options: &Context) -> TokenStream {
+    let specified_type = &conversion_type.ty;
+    let from_fn_name = conversion_type.checked_from_name(options);
+    let fn_name = conversion_type.method_name("from_", "_flow");
+    let must_use_attr = must_use_attr(options);
+
+    // Conversions matching every value always continue
+    let body = if conversion_type.never_fails(options) {
+        quote! { ::core::ops::ControlFlow::Continue(Self::#from_fn_name(value)) }
+    } else {
+        quote! {
+            match Self::#from_fn_name(value) {
+                Some(variant) => ::core::ops::ControlFlow::Continue(variant),
+                None => ::core::ops::ControlFlow::Break(value),
+            }
+        }
+    };
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a raw #specified_type integer value to a `ControlFlow`.
+        ///
+        /// Returns `Continue(variant)` if the value matches the discriminant (when
+        /// cast to #specified_type) of a variant. Returns `Break(value)` otherwise.
+        pub fn #fn_name(
+            value: #specified_type,
+        ) -> ::core::ops::ControlFlow<#specified_type, Self> {
+            #body
+        }
+    }
+}
+
+// Generates the `decode_*` method, which extracts the tag field from a packed word and
+// returns the matching variant along with the rest of the word, with the tag bits cleared
+fn generate_decode_method(
+    decode: &Decode,
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<TokenStream> {
+    // A `bool` has no bits to pack a tag into
+    if conversion_type.name == "bool" || !decode_fits(decode, conversion_type) {
+        return None;
+    }
+    let specified_type = &conversion_type.ty;
+    let from_fn_name = conversion_type.checked_from_name(options);
+    let fn_name = conversion_type.method_name("decode_", "");
+    let must_use_attr = must_use_attr(options);
+    let shift = Literal::u32_unsuffixed(decode.shift);
+    let mask = Literal::u128_unsuffixed(decode.mask);
+
+    let result = if conversion_type.never_fails(options) {
+        quote! { Some((Self::#from_fn_name(tag), payload)) }
+    } else {
+        quote! { Self::#from_fn_name(tag).map(|variant| (variant, payload)) }
+    };
+
+    Some(quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Decodes a packed #specified_type word, whose tag field is matched against
+        /// the discriminants, returning the variant along with the rest of the word
+        /// (with the tag bits cleared).
+        ///
+        /// Returns `None` if the tag doesn't match the discriminant of any variant.
+        pub fn #fn_name(word: #specified_type) -> Option<(Self, #specified_type)> {
+            const MASK: #specified_type = #mask;
+            let tag = (word >> #shift) & MASK;
+            let payload = word & !(MASK << #shift);
+            #result
+        }
+    })
+}
+
+// Returns whether the tag field of `decode` lies within the bits of a type, on every
+// target. The mask must also be a positive value of the type, so for a signed type it
+// can't cover the sign bit.
+fn decode_fits(decode: &Decode, conversion_type: &ConversionType) -> bool {
+    // `c_char` has 8 bits, of a signedness depending on the target
+    let (signed, bits, _) = integer_width(&conversion_type.name).unwrap_or((true, 8, 8));
+    let mask_bits = u128::BITS - decode.mask.leading_zeros();
+    decode.shift < bits && decode.shift + mask_bits <= bits && (!signed || mask_bits < bits)
+}
+
+// Returns the name of a variant as written, without the `r#` prefix of raw identifiers
+fn variant_str(variant: &Variant) -> String {
+    variant.ident.unraw().to_string()
+}
+
+// The variant-level options, given as `#[rawenum(...)]` attributes on a variant
+#[derive(Default)]
+struct VariantOptions {
+    // The `wire = <integer literal>` override, along with its value
+    wire: Option<(Expr, i128)>,
+    // The `alias = <integer literal>` values, along with their values
+    aliases: Vec<(Expr, i128)>,
+    // Whether the variant is marked `catch_all`
+    catch_all: bool,
+    // Whether the variant is marked `skip`
+    skip: bool,
+}
+
+// Parses the variant-level options of a variant
+fn variant_options(variant: &Variant) -> syn::Result<VariantOptions> {
+    let mut options = VariantOptions::default();
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("rawenum"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("catch_all") {
+                options.catch_all = true;
+                return Ok(());
+            }
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+                return Ok(());
+            }
+            // `value` is another name for `wire`
+            let is_wire = meta.path.is_ident("wire") || meta.path.is_ident("value");
+            if !is_wire && !meta.path.is_ident("alias") {
+                return Err(meta.error(
+                    "unknown variant option, expected `wire = <integer literal>`, `alias = <integer literal>`, `catch_all` or `skip`",
+                ));
+            }
+            let expr: Expr = meta.value()?.parse()?;
+            let Some(value) = int_literal_value(&expr)? else {
+                return Err(syn::Error::new_spanned(
+                    &expr,
+                    "expected an integer literal",
+                ));
+            };
+            if is_wire {
+                options.wire = Some((expr, value));
+            } else {
+                options.aliases.push((expr, value));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+// Parses the `wire = <integer literal>` override of a variant, given as a variant-level
+// `#[rawenum(wire = ...)]` attribute, returning the literal along with its value
+fn wire_value(variant: &Variant) -> syn::Result<Option<(Expr, i128)>> {
+    Ok(variant_options(variant)?.wire)
+}
+
+// Returns the additional values a variant is matched by, given as variant-level
+// `#[rawenum(alias = ...)]` attributes. Invalid attributes are reported by `generate`.
+fn alias_values(variant: &Variant) -> Vec<(Expr, i128)> {
+    variant_options(variant)
+        .map(|options| options.aliases)
+        .unwrap_or_default()
+}
+
+// Returns whether a variant is marked with the variant-level `#[rawenum(catch_all)]`
+// attribute. Invalid attributes are reported by `generate`.
+fn is_catch_all(variant: &Variant) -> bool {
+    variant_options(variant).is_ok_and(|options| options.catch_all)
+}
+
+// Returns whether a variant is marked with the variant-level `#[rawenum(skip)]`
+// attribute. Invalid attributes are reported by `generate`.
+fn is_skipped(variant: &Variant) -> bool {
+    variant_options(variant).is_ok_and(|options| options.skip)
+}
+
+// Evaluates an integer literal, optionally negated, returning `None` for any other
+// expression
+fn int_literal_value(expr: &Expr) -> syn::Result<Option<i128>> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => Ok(Some(lit.base10_parse()?)),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match &**inner {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => {
+                // The magnitude is parsed unsigned, so that `i128::MIN` can be negated
+                let magnitude: u128 = lit.base10_parse()?;
+                match 0i128.checked_sub_unsigned(magnitude) {
+                    Some(value) => Ok(Some(value)),
+                    None => Err(syn::Error::new_spanned(
+                        lit,
+                        "number too large to fit in target type",
+                    )),
+                }
+            }
+            _ => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
+// Evaluates the discriminant of each variant, when it's an integer literal or follows one
+// implicitly, returning `None` for the discriminants that can't be evaluated
+fn literal_discriminants(variants: &[&Variant]) -> syn::Result<Vec<Option<i128>>> {
+    let mut next_discriminant = Some(0);
+    variants
+        .iter()
+        .map(|variant| {
+            let discriminant = match &variant.discriminant {
+                Some((_, expr)) => int_literal_value(expr)?,
+                None => next_discriminant,
+            };
+            // The compiler rejects an implicit discriminant following `i128::MAX` anyway
+            next_discriminant = discriminant.map(|value| value.wrapping_add(1));
+            Ok(discriminant)
+        })
+        .collect()
+}
+
+// Ensures that no two of the values matched by the conversions from a type collide once
+// cast to it, in which case the first of them would shadow the other. When every value is
+// a literal, and the range of the type is known, the values are compared right away, so
+// the error names both variants. Otherwise, they are compared by a const assertion, which
+// is returned.
+fn check_collisions(
+    name: &Ident,
+    variants: &[&Variant],
+    target: &TargetType,
+    options: &Context,
+) -> syn::Result<Option<TokenStream>> {
+    let conversion_type = ConversionType::new(&target.ty, options)?;
+    // Every discriminant fits in the 128-bit types, and they are cast to them without
+    // losing information, so they can't collide
+    if matches!(conversion_type.name.as_str(), "i128" | "u128") {
+        return Ok(None);
+    }
+    let assertion = || {
+        Ok(Some(generate_collision_assertion(
+            name,
+            variants,
+            target,
+            &conversion_type,
+            options,
+        )))
+    };
+
+    // The values in the order of the match arms, with the aliases after all of the
+    // variants' own values
+    let mut values: Vec<(&Variant, i128)> = Vec::new();
+    let mut aliases: Vec<(&Variant, i128)> = Vec::new();
+    for variant in variants {
+        let Some((value, variant_aliases)) = literal_values(variant, options) else {
+            return assertion();
+        };
+        values.push((variant, value));
+        aliases.extend(variant_aliases.into_iter().map(|alias| (*variant, alias)));
+    }
+
+    // The `lossy_methods` option matches the values that `strict` leaves out in
+    // `from_*_lossy` anyway
+    let strict = options.strict && !options.lossy_methods;
+    let mut matched: Vec<(&Variant, i128, i128)> = Vec::new();
+    for (variant, value) in values.into_iter().chain(aliases) {
+        let Some(cast) = conversion_type.cast_literal(value) else {
+            return assertion();
+        };
+        if !conversion_type.matches_literal(value, cast, strict) {
+            continue;
+        }
+        if let Some((other, other_value, _)) = matched
+            .iter()
+            .find(|(other, _, other_cast)| *other_cast == cast && other.ident != variant.ident)
+        {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "`{}` ({}) and `{}` ({}) both become {} when cast to `{}`, so `{}` would never convert to `{}`",
+                    other.ident,
+                    other_value,
+                    variant.ident,
+                    value,
+                    cast,
+                    conversion_type.name,
+                    conversion_type.method_name("from_", ""),
+                    variant.ident,
+                ),
+            ));
+        }
+        matched.push((variant, value, cast));
+    }
+    Ok(None)
+}
+
+// Returns the value a variant is matched by, along with its aliases, when the macro can
+// evaluate them: the `wire` value or the literal discriminant, for variants that aren't
+// gated behind a `cfg`, which might never be compiled in together with the others
+fn literal_values(variant: &Variant, options: &Context) -> Option<(i128, Vec<i128>)> {
+    if !variant_cfgs(variant).is_empty() {
+        return None;
+    }
+    let variant_options = variant_options(variant).ok()?;
+    let value = match variant_options.wire {
+        Some((_, value)) => value,
+        None => *options
+            .literal_discriminants
+            .get(&variant.ident.to_string())?,
+    };
+    let aliases = variant_options
+        .aliases
+        .into_iter()
+        .map(|(_, alias)| alias)
+        .collect();
+    Some((value, aliases))
+}
+
+// Generates the const assertion of `check_collisions`, for the values the macro can't
+// evaluate. Const evaluation can't format the names of both variants into the message, so
+// it only names the shadowed one.
+fn generate_collision_assertion(
+    name: &Ident,
+    variants: &[&Variant],
+    target: &TargetType,
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> TokenStream {
+    let const_type = conversion_type.const_type();
+    let fn_name = conversion_type.method_name("from_", "");
+    // Each value is the variant's own value, followed by its aliases, like the match arms
+    let values: Vec<(usize, &Variant, TokenStream)> = variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| (index, *variant, raw_value(name, variant, options)))
+        .chain(variants.iter().enumerate().flat_map(|(index, variant)| {
+            alias_values(variant)
+                .into_iter()
+                .map(move |(alias, _)| (index, *variant, alias.to_token_stream()))
+        }))
+        .collect();
+    let value_count = values.len();
+    let pushes = values.iter().map(|(index, variant, raw_value)| {
+        let cfgs = variant_cfgs(variant);
+        let matched = conversion_type
+            .matches_value(raw_value, options.strict && !options.lossy_methods);
+        let message = format!(
+            "`{}` has the same value as another variant when cast to `{}`, so `{}` would never convert to it",
+            variant.ident, conversion_type.name, fn_name,
+        );
+        quote! {
+            #cfgs
+            {
+                if #matched {
+                    values[len] = (#raw_value as i128 as #const_type, #index, #message);
+                    len += 1;
+                }
+            }
+        }
+    });
+    let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+    quote! {
+        #cfg_attr
+        #[allow(deprecated, unused_mut)]
+        const _: () = {
+            let mut values: [(#const_type, usize, &str); #value_count] = [(0, 0, ""); #value_count];
+            let mut len = 0;
+            #( #pushes )*
+            let mut i = 0;
+            while i < len {
+                let mut j = i + 1;
+                while j < len {
+                    if values[i].0 == values[j].0 && values[i].1 != values[j].1 {
+                        panic!("{}", values[j].2);
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+    }
+}
+
+// Ensures, for the `strict_types` option, that every type can losslessly hold the value
+// of every variant. The discriminants are evaluated here, so they must be literals, with
+// implicit discriminants following the previous one. The range of the integer primitives
+// is checked right away, while the C type aliases, whose range depends on the target, are
+// checked by const assertions, which are returned.
+fn check_strict_types(variants: &[&Variant], options: &Options) -> syn::Result<TokenStream> {
+    // The value of each variant, along with its name
+    let mut values: Vec<(i128, &Ident)> = Vec::new();
+    for (variant, discriminant) in variants.iter().zip(literal_discriminants(variants)?) {
+        // The first discriminant that can't be evaluated is always an explicit one
+        let Some(discriminant) = discriminant else {
+            return Err(syn::Error::new_spanned(
+                variant.discriminant.as_ref().map(|(_, expr)| expr),
+                "the `strict_types` option requires integer literal discriminants",
+            ));
+        };
+        let value = match wire_value(variant)? {
+            Some((_, value)) => value,
+            None => discriminant,
+        };
+        values.push((value, &variant.ident));
+        // The aliases are converted too
+        for (_, alias) in alias_values(variant) {
+            values.push((alias, &variant.ident));
+        }
+    }
+    // The range a type needs in order to hold every value
+    let needed_min = values.iter().map(|(value, _)| *value).min().unwrap_or(0);
+    let needed_max = values.iter().map(|(value, _)| *value).max().unwrap_or(0);
+
+    let mut assertions = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        let bounds = if conversion_type.name == "bool" {
+            Some((0, 1))
+        } else {
+            conversion_type.primitive_bounds()
+        };
+        match bounds {
+            Some((min, max)) => {
+                if let Some((value, variant)) = values
+                    .iter()
+                    .find(|(value, _)| *value < min || *value > max)
+                {
+                    return Err(syn::Error::new_spanned(
+                        &target.ty,
+                        format!(
+                            "`{}` cannot losslessly hold the value {} of `{}`, the values of the variants need the range {}..={}",
+                            conversion_type.name, value, variant, needed_min, needed_max,
+                        ),
+                    ));
+                }
+            }
+            None => {
+                let (min, max) = conversion_type.bounds().expect("bool is handled above");
+                let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+                let message = format!(
+                    "`{}` cannot losslessly hold the value of every variant",
+                    conversion_type.name
+                );
+                assertions.push(quote! {
+                    #cfg_attr
+                    const _: () = assert!(#needed_min >= #min && #needed_max <= #max, #message);
+                });
+            }
+        }
+    }
+    Ok(quote! { #( #assertions )* })
+}
+
+// Returns the value a variant is matched against, before it is cast to a type: its
+// `wire` override if it has one, or its discriminant otherwise. `enum_path` is how the
+// generated code refers to the enum, e.g. `Self`.
+//
+// An enum with a data-carrying catch-all variant can't be cast to an integer, so its
+// discriminants are read through its primitive `repr` instead, which lays the
+// discriminant out at the start of the enum.
+fn raw_value(enum_path: impl ToTokens, variant: &Variant, options: &Context) -> TokenStream {
+    match wire_value(variant) {
+        Ok(Some((wire, _))) => quote! { #wire },
+        // Invalid overrides were already reported by `generate`
+        _ => {
+            let variant_name = &variant.ident;
+            match &options.data_repr {
+                Some(repr) => quote! {
+                    (unsafe { *(&#enum_path::#variant_name as *const #enum_path).cast::<#repr>() })
+                },
+                None => quote! { #enum_path::#variant_name },
+            }
+        }
+    }
+}
+
+// Returns the `cfg` attributes of a variant, along with its `cfg_attr` attributes that only
+// expand to `cfg` attributes, so that the code generated for it is compiled out along with it
+fn variant_cfgs(variant: &Variant) -> TokenStream {
+    let cfgs = variant.attrs.iter().filter(|attr| {
+        if attr.path().is_ident("cfg") {
+            return true;
+        }
+        attr.path().is_ident("cfg_attr")
+            && attr
+                .parse_args_with(|input: syn::parse::ParseStream| {
+                    let _predicate: Meta = input.parse()?;
+                    input.parse::<Token![,]>()?;
+                    Punctuated::<Meta, Token![,]>::parse_terminated(input)
+                })
+                .is_ok_and(|attrs| attrs.iter().all(|attr| attr.path().is_ident("cfg")))
+    });
+    quote! { #( #cfgs )* }
+}
+
+// Returns every value a variant is matched by, before it is cast to a type: its value as
+// returned by `raw_value`, followed by its aliases
+fn matched_values(
+    enum_path: impl ToTokens,
+    variant: &Variant,
+    options: &Context,
+) -> Vec<TokenStream> {
+    let mut values = vec![raw_value(&enum_path, variant, options)];
+    values.extend(
+        alias_values(variant)
+            .into_iter()
+            .map(|(alias, _)| alias.to_token_stream()),
+    );
+    values
+}
+
+// Returns the enum's primitive representation along with the first and the last value of
+// the run, if the values of the convertible variants are exactly the discriminants of a
+// contiguous run, so that the conversions can transmute any value within it. The values
+// must be literals that aren't gated behind a `cfg`, overridden by `wire` or extended by
+// aliases, and the enum must be fieldless.
+fn contiguous_run(
+    input: &DeriveInput,
+    variants: &[&Variant],
+    options: &Context,
+) -> Option<(Ident, i128, i128)> {
+    if options.catch_all_payload.is_some() || variants.len() < 2 {
+        return None;
+    }
+    let repr = primitive_repr(&input.attrs)?;
+    let mut values = Vec::new();
+    for variant in variants {
+        let variant_options = variant_options(variant).ok()?;
+        if variant_options.wire.is_some() || !variant_options.aliases.is_empty() {
+            return None;
+        }
+        let (value, _) = literal_values(variant, options)?;
+        values.push(value);
+    }
+    values.sort_unstable();
+    let (first, last) = (values[0], values[values.len() - 1]);
+    // The discriminants are distinct, so they are contiguous if they span as many values
+    (last.checked_sub(first)? == values.len() as i128 - 1).then_some((repr, first, last))
+}
+
+// Picks the strategy for `strategy = "auto"` from the values of the convertible variants.
+// Small enums, dense ones and contiguous runs are matched, since the compiler turns those
+// matches into jump tables or range checks. Larger sparse enums are binary searched, and
+// the largest ones are looked up in a perfect hash map when the `phf` feature is enabled.
+// Enums whose values the macro can't evaluate, and enums with a lookup table, are matched.
+fn auto_strategy(variants: &[&Variant], contiguous: bool, options: &Context) -> Strategy {
+    if contiguous || options.table {
+        return Strategy::Match;
+    }
+    let mut values = Vec::new();
+    for variant in variants {
+        let Some((value, aliases)) = literal_values(variant, options) else {
+            return Strategy::Match;
+        };
+        values.push(value);
+        values.extend(aliases);
+    }
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return Strategy::Match;
+    };
+    let count = values.len() as u128;
+    let span = max.abs_diff(min).saturating_add(1);
+    // At least every other value in the span matches a variant
+    if count < AUTO_MATCH_MAX_VALUES || count.saturating_mul(2) >= span {
+        Strategy::Match
+    } else if count >= AUTO_PHF_MIN_VALUES && cfg!(feature = "phf") && !options.const_fn {
+        Strategy::Phf
+    } else {
+        Strategy::BinarySearch
+    }
+}
+
+// Checks that the field of a catch-all variant is an integer type that can hold every
+// value of every type, since unmatched values are cast to it
+fn check_catch_all_payload(payload: &Type, options: &Options) -> syn::Result<()> {
+    // The C type aliases are usually written as paths, e.g. `core::ffi::c_int`
+    let payload_name = match payload {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .filter(|name| name != "bool" && integer_width(name).is_some()),
+        _ => None,
+    };
+    let Some(payload_name) = payload_name else {
+        return Err(syn::Error::new_spanned(
+            payload,
+            "a catch-all variant can only hold an integer type, e.g. `u32`",
+        ));
+    };
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        if !holds_losslessly(&payload_name, &conversion_type.name) {
+            return Err(syn::Error::new_spanned(
+                payload,
+                format!(
+                    "the catch-all variant holds `{}`, which can't hold every value of `{}`, so unmatched values would be truncated",
+                    payload_name, conversion_type.name
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Returns whether an integer type can be signed, along with the least and the most bits
+// it can have on any target, or `None` if it isn't an integer type or its signedness
+// depends on the target, like `c_char`. `bool` holds a single bit.
+fn integer_width(name: &str) -> Option<(bool, u32, u32)> {
+    let (signed, bits) = match name {
+        "bool" => (false, 1),
+        "i8" | "c_schar" => (true, 8),
+        "u8" | "c_uchar" => (false, 8),
+        "i16" | "c_short" => (true, 16),
+        "u16" | "c_ushort" => (false, 16),
+        "i32" => (true, 32),
+        "u32" => (false, 32),
+        "i64" | "c_longlong" => (true, 64),
+        "u64" | "c_ulonglong" => (false, 64),
+        "i128" => (true, 128),
+        "u128" => (false, 128),
+        "isize" => return Some((true, 16, 64)),
+        "usize" => return Some((false, 16, 64)),
+        "c_int" => return Some((true, 16, 32)),
+        "c_uint" => return Some((false, 16, 32)),
+        "c_long" => return Some((true, 32, 64)),
+        "c_ulong" => return Some((false, 32, 64)),
+        _ => return None,
+    };
+    Some((signed, bits, bits))
+}
+
+// Returns whether the `holder` type can hold every value of the `held` type, on every
+// target
+fn holds_losslessly(holder: &str, held: &str) -> bool {
+    if holder == held {
+        return true;
+    }
+    let (Some((holder_signed, holder_bits, _)), Some((held_signed, _, held_bits))) =
+        (integer_width(holder), integer_width(held))
+    else {
+        return false;
+    };
+    match (held_signed, holder_signed) {
+        (false, false) | (true, true) => held_bits <= holder_bits,
+        // An unsigned type needs a sign bit on top of its bits
+        (false, true) => held_bits < holder_bits,
+        (true, false) => false,
+    }
+}
+
+// Returns the primitive integer type given by an enum's `#[repr(...)]` attribute, if any
+fn primitive_repr(attrs: &[Attribute]) -> Option<Ident> {
+    let mut repr = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            // Every supported type except the `bool` pseudo-type can be a representation
+            if let Some(ident) = meta.path.get_ident()
+                && ident != "bool"
+                && SUPPORTED_TYPES.contains(&ident.to_string().as_str())
+            {
+                repr = Some(ident.clone());
+            }
+            Ok(())
+        });
+    }
+    repr
+}
+
+// Generates the slice helpers for a type: `from_any_*`, returning the variant matching
+// the first value in a slice that matches any variant, and `any_matches_*`, checking
+// whether the variant's discriminant is in a slice.
+fn generate_slice_helpers(
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> [TokenStream; 2] {
+    let specified_type = &conversion_type.ty;
+    let const_type = conversion_type.const_type();
+    let match_value = conversion_type.match_value();
+    let from_fn_name = conversion_type.checked_from_name(options);
+    let from_any_fn_name = conversion_type.method_name("from_any_", "");
+    let any_matches_fn_name = conversion_type.method_name("any_matches_", "");
+    let must_use_attr = must_use_attr(options);
+
+    // Conversions matching every value match the first value
+    let from_any_body = if conversion_type.never_fails(options) {
+        quote! { values.first().map(|&value| Self::#from_fn_name(value)) }
+    } else {
+        quote! { values.iter().find_map(|&value| Self::#from_fn_name(value)) }
+    };
+
+    let discriminant_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let raw_value = raw_value(quote! { Self }, variant, options);
+        let cfgs = variant_cfgs(variant);
+        quote! {
+            #cfgs
+            Self::#variant_name => #raw_value as i128 as #const_type,
+        }
+    });
+
+    [
+        quote! {
+            #must_use_attr
+            #[allow(dead_code)] // Allow this function to be unused without a warning
+            /// Returns the variant matching the first value in `values` that matches the
+            /// discriminant (when cast to #specified_type) of a variant.
+            ///
+            /// Returns `None` if no value matches, including when `values` is empty.
+            pub fn #from_any_fn_name(values: &[#specified_type]) -> Option<Self> {
+                #from_any_body
+            }
+        },
+        quote! {
+            #must_use_attr
+            #[allow(dead_code)] // Allow this function to be unused without a warning
+            /// Returns whether any value in `values` matches the variant's discriminant
+            /// (when cast to #specified_type).
+            pub fn #any_matches_fn_name(&self, values: &[#specified_type]) -> bool {
+                let discriminant = match self {
+                    #( #discriminant_arms )*
+                };
+                values.iter().any(|&value| #match_value == discriminant)
+            }
+        },
+    ]
+}
+
+// Generates the `assert_distinct_*` const fn, which checks at const-eval time that no
+// two variants have the same discriminant when cast to the type. Unlike the macro,
+// const evaluation can compute arbitrary discriminant expressions.
+fn generate_assert_distinct_method(
+    name: &Ident,
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> TokenStream {
+    let const_type = conversion_type.const_type();
+    let fn_name = conversion_type.method_name("assert_distinct_", "");
+    let variant_count = variants.len();
+    let discriminants = variants.iter().map(|variant| {
+        let raw_value = raw_value(name, variant, options);
+        quote! { #raw_value as i128 as #const_type }
+    });
+
+    quote! {
+        #[must_use]
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns whether all variants have distinct discriminants when cast to
+        /// #specified_type.
+        ///
+        /// This is a `const fn`, so the check can be enforced at compile time with
+        /// `const _: () = assert!(MyEnum::assert_distinct_<type>());`.
+        pub const fn #fn_name() -> bool {
+            const DISCRIMINANTS: [#const_type; #variant_count] = [#( #discriminants ),*];
+            let mut i = 0;
+            while i < DISCRIMINANTS.len() {
+                let mut j = i + 1;
+                while j < DISCRIMINANTS.len() {
+                    if DISCRIMINANTS[i] == DISCRIMINANTS[j] {
+                        return false;
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+            true
+        }
+    }
+}
+
+// Generates the `sorted_entries_*` method, returning every variant along with its
+// discriminant cast to the type, in ascending order of the cast discriminants. The
+// entries are sorted at const-eval time, so it works for any discriminant expression,
+// and ties keep their declaration order.
+fn generate_sorted_entries_method(
+    name: &Ident,
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<TokenStream> {
+    // Discriminants can't be cast to `bool`, so there's no meaningful order
+    if conversion_type.name == "bool" {
+        return None;
+    }
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("sorted_entries_", "");
+    let variant_count = variants.len();
+
+    // Nested items can't refer to `Self`, so they name the enum directly
+    let keys = variants.iter().map(|variant| {
+        let raw_value = raw_value(name, variant, options);
+        quote! { #raw_value as i128 as #specified_type }
+    });
+    let variant_arms = variants.iter().enumerate().map(|(index, variant)| {
+        let variant_name = &variant.ident;
+        quote! { #index => #name::#variant_name, }
+    });
+    let entries = (0..variant_count).map(|position| {
+        quote! { (KEYS[ORDER[#position]], variant(ORDER[#position])) }
+    });
+
+    Some(quote! {
+        #[must_use]
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns every variant along with its discriminant (when cast to
+        /// #specified_type), sorted in ascending order of the discriminants.
+        pub fn #fn_name() -> &'static [(#specified_type, Self)] {
+            const KEYS: [#specified_type; #variant_count] = [#( #keys ),*];
+
+            // The declaration indices of the variants, insertion-sorted by their keys
+            const ORDER: [usize; #variant_count] = {
+                let mut order = [0; #variant_count];
+                let mut i = 0;
+                while i < order.len() {
+                    order[i] = i;
+                    i += 1;
+                }
+                let mut i = 1;
+                while i < order.len() {
+                    let mut j = i;
+                    while j > 0 && KEYS[order[j - 1]] > KEYS[order[j]] {
+                        let previous = order[j - 1];
+                        order[j - 1] = order[j];
+                        order[j] = previous;
+                        j -= 1;
+                    }
+                    i += 1;
+                }
+                order
+            };
+
+            // Constructs the variant with the given declaration index
+            const fn variant(index: usize) -> #name {
+                match index {
+                    #( #variant_arms )*
+                    _ => unreachable!(),
+                }
+            }
+
+            static ENTRIES: [(#specified_type, #name); #variant_count] = [#( #entries ),*];
+            &ENTRIES
+        }
+    })
+}
+
+// Generates the `to_*` method, the reverse of `from_*`, which casts the variant's value to
+// the type. Every variant has a value, so it covers the variants excluded by `only` too.
+fn generate_to_method(
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<TokenStream> {
+    // A discriminant can't be cast to `bool`
+    if conversion_type.name == "bool" {
+        return None;
+    }
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("to_", "");
+    let must_use_attr = must_use_attr(options);
+
+    let value_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let raw_value = raw_value(quote! { Self }, variant, options);
+        let cfgs = variant_cfgs(variant);
+        quote! {
+            #cfgs
+            Self::#variant_name => #raw_value as i128 as #specified_type,
+        }
+    });
+    // A catch-all variant holding the raw value converts back to it
+    let catch_all_arm = options
+        .catch_all
+        .as_ref()
+        .filter(|_| options.catch_all_payload.is_some())
+        .map(|catch_all| {
+            quote! {
+                Self::#catch_all(value) => *value as i128 as #specified_type,
+            }
+        });
+
+    Some(quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts the variant to a raw #specified_type integer value, its
+        /// discriminant cast to #specified_type.
+        pub const fn #fn_name(&self) -> #specified_type {
+            match self {
+                #( #value_arms )*
+                #catch_all_arm
+            }
+        }
+    })
+}
+
+// Generates the `DISCRIMINANTS_*` const, listing the value of every variant, cast to the
+// type like `to_*` does, along with its name, in declaration order
+fn generate_discriminants_const(
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<TokenStream> {
+    // A discriminant can't be cast to `bool`
+    if conversion_type.name == "bool" {
+        return None;
+    }
+    let specified_type = &conversion_type.ty;
+    let const_name = format_ident!(
+        "DISCRIMINANTS_{}{}",
+        conversion_type.name.to_uppercase(),
+        conversion_type.name_suffix.to_uppercase(),
+        span = conversion_type.span
+    );
+    let variant_count = variants.len();
+    let entries = variants.iter().map(|variant| {
+        let raw_value = raw_value(quote! { Self }, variant, options);
+        let variant_str = variant_str(variant);
+        quote! { (#raw_value as i128 as #specified_type, #variant_str) }
+    });
+
+    Some(quote! {
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        /// The value of every variant (when cast to #specified_type) along with its
+        /// name, in declaration order.
+        pub const #const_name: [(#specified_type, &'static str); #variant_count] =
+            [#( #entries ),*];
+    })
+}
+
+// Generates a public associated const for each variant, holding its value cast to the
+// type like `to_*` does, for match patterns, array lengths and FFI. The consts are named
+// by the `consts` name scheme, from the variant's name in SCREAMING_SNAKE_CASE and the
+// type's name in uppercase.
+fn generate_value_consts(
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    scheme: &LitStr,
+    options: &Context,
+) -> Vec<TokenStream> {
+    // A discriminant can't be cast to `bool`
+    if conversion_type.name == "bool" {
+        return Vec::new();
+    }
+    let specified_type = &conversion_type.ty;
+    let type_name = conversion_type.name.to_uppercase();
+
+    variants
+        .iter()
+        .map(|variant| {
+            let const_name = Ident::new(
+                &scheme
+                    .value()
+                    .replace("{VARIANT}", &screaming_snake_case(&variant_str(variant)))
+                    .replace("{TYPE}", &type_name),
+                variant.ident.span(),
+            );
+            let raw_value = raw_value(quote! { Self }, variant, options);
+            let cfgs = variant_cfgs(variant);
+            let doc = format!(
+                " The value of `{}`, cast to `{}`.",
+                variant_str(variant),
+                conversion_type.name
+            );
+            quote! {
+                #cfgs
+                #[doc = #doc]
+                pub const #const_name: #specified_type = #raw_value as i128 as #specified_type;
+            }
+        })
+        .collect()
+}
+
+// Converts a name from PascalCase to SCREAMING_SNAKE_CASE, keeping acronyms together, so
+// that `HttpError` and `HTTPError` both become `HTTP_ERROR`
+fn screaming_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_uppercase());
+    }
+    result
+}
+
+// Generates the `to_*_saturating` method, which converts the discriminant to the type,
+// clamping it to the type's range instead of wrapping. The bounds of the primitive
+// types are computed at macro time, so the comparisons are against literals.
+fn generate_to_saturating_method(
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Options,
+) -> Option<TokenStream> {
+    // There's no meaningful saturation into `bool`
+    let (min, max) = conversion_type.bounds()?;
+    let specified_type = &conversion_type.ty;
+    let fn_name = conversion_type.method_name("to_", "_saturating");
+    let must_use_attr = must_use_attr(options);
+
+    let discriminant_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let cfgs = variant_cfgs(variant);
+        quote! {
+            #cfgs
+            Self::#variant_name => Self::#variant_name as i128,
+        }
+    });
+
+    Some(quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts the variant's discriminant to a raw #specified_type integer value,
+        /// clamping it to the range of #specified_type instead of wrapping.
+        pub fn #fn_name(&self) -> #specified_type {
+            let discriminant: i128 = match self {
+                #( #discriminant_arms )*
+            };
+            if discriminant < #min {
+                #min as #specified_type
+            } else if discriminant > #max {
+                #max as #specified_type
+            } else {
+                discriminant as #specified_type
+            }
+        }
+    })
+}
+
+// Dropping the result of a conversion is almost always a bug, unless opted out
+fn must_use_attr(options: &Options) -> Option<TokenStream> {
+    (!options.no_must_use).then(|| quote! { #[must_use] })
+}
+
+// Generates a test, only compiled in test builds, asserting that every variant
+// round-trips through the `from_*` method of each type. This catches discriminants
+// that collide or get truncated when cast to a type.
+fn generate_roundtrip_test(
+    name: &Ident,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let test_name = format_ident!("__rawenum_roundtrip_{}", name.unraw());
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let mut assertions = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+
+        // Discriminants can't be cast to `bool`, so there's nothing to round-trip
+        if conversion_type.name == "bool" {
+            continue;
+        }
+
+        let specified_type = &conversion_type.ty;
+        let fn_name = conversion_type.method_name("from_", "");
+        let returns_self = conversion_type.returns_self(options);
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+        for variant in variants {
+            let variant_name = &variant.ident;
+            let raw_value = raw_value(name, variant, options);
+            let message = format!(
+                "variant `{}` does not round-trip through {}",
+                variant_str(variant),
+                conversion_type.name
+            );
+            let expected = if returns_self {
+                quote! { #name::#variant_name }
+            } else {
+                quote! { Some(#name::#variant_name) }
+            };
+            // With the `strict` option, variants that don't fit the type aren't matched by it
+            let condition = if options.strict {
+                conversion_type.fits(&raw_value)
+            } else {
+                quote! { true }
+            };
+            let cfgs = variant_cfgs(variant);
+            assertions.push(quote! {
+                #cfg_attr
+                #cfgs
+                if #condition {
+                    assert_eq!(
+                        #functions::#fn_name(#raw_value as i128 as #specified_type),
+                        #expected,
+                        #message
+                    );
+                }
+            });
+        }
+    }
+
+    Ok(quote! {
+        #[cfg(test)]
+        #[test]
+        #[allow(non_snake_case)]
+        fn #test_name() {
+            #( #assertions )*
+        }
+    })
+}
+
+// The types small enough for the property test to check every value of
+const PROPERTY_TEST_TYPES: &[&str] = &["i8", "u8", "i16", "u16"];
+
+// Generates a test, only compiled in test builds, checking every value of each type in
+// `PROPERTY_TEST_TYPES`: the `from_*` method must match the value iff it is one of the
+// cast discriminants, which are collected independently of the generated match.
+fn generate_property_test(
+    input: &DeriveInput,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let test_name = format_ident!("__rawenum_property_{}", name.unraw());
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let mut checks = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+
+        // Larger types have too many values to check them all
+        if !PROPERTY_TEST_TYPES.contains(&conversion_type.name.as_str()) {
+            continue;
+        }
+
+        let specified_type = &conversion_type.ty;
+        let fn_name = conversion_type.method_name("from_", "");
+        let returns_self = conversion_type.returns_self(options);
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+        // Each cast value, along with whether it is matched, which with the `strict`
+        // option only holds for the values that fit the type
+        let expected_values: Vec<TokenStream> = variants
+            .iter()
+            .flat_map(|variant| matched_values(name, variant, options))
+            .map(|value| {
+                let matched = if options.strict {
+                    conversion_type.fits(&value)
+                } else {
+                    quote! { true }
+                };
+                quote! { (#value as i128 as #specified_type, #matched) }
+            })
+            .collect();
+        let variant_count = expected_values.len();
+        let message = format!("{} matches the wrong set of values", fn_name);
+
+        // Exhaustive conversions match every value by construction
+        let matched = if returns_self {
+            quote! {{
+                let _ = #functions::#fn_name(value);
+                true
+            }}
+        } else {
+            quote! { #functions::#fn_name(value).is_some() }
+        };
+
+        checks.push(quote! {
+            #cfg_attr
+            {
+                let expected_values: [(#specified_type, bool); #variant_count] = [#( #expected_values ),*];
+                for value in <#specified_type>::MIN..=<#specified_type>::MAX {
+                    assert_eq!(
+                        #matched,
+                        expected_values.contains(&(value, true)),
+                        "{} for value {}",
+                        #message,
+                        value
+                    );
+                }
+            }
+        });
+    }
+
+    if checks.is_empty() {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!(
+                "the `property_test` option requires one of the types {}",
+                PROPERTY_TEST_TYPES.join(", ")
+            ),
+        ));
+    }
+
+    Ok(quote! {
+        #[cfg(test)]
+        #[test]
+        #[allow(non_snake_case)]
+        fn #test_name() {
+            #( #checks )*
+        }
+    })
+}
+
+// Generates the lookup of the `binary_search` strategy, which binary searches a sorted
+// array of the values matched by the conversions from the type, each along with the index
+// of the variant it converts to, and leaves the index of the variant matching `value` (if
+// any) in `index`. The array is sorted at const-eval time, so it works for any
+// discriminant expression, and the lookup is a loop rather than a call, so it works in a
+// `const fn`.
+fn generate_binary_search(
+    name: &Ident,
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> TokenStream {
+    let const_type = conversion_type.const_type();
+    let match_value = conversion_type.match_value();
+
+    // Each value is the variant's own value, followed by its aliases, like the match arms.
+    // Nested items can't refer to `Self`, so they name the enum directly.
+    let values: Vec<TokenStream> = variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| (index, raw_value(name, variant, options)))
+        .chain(variants.iter().enumerate().flat_map(|(index, variant)| {
+            alias_values(variant)
+                .into_iter()
+                .map(move |(alias, _)| (index, alias.to_token_stream()))
+        }))
+        .map(|(index, raw_value)| {
+            let matched = conversion_type.matches_value(&raw_value, options.strict);
+            quote! { (#raw_value as i128 as #const_type, #index, #matched) }
+        })
+        .collect();
+    let value_count = values.len();
+
+    quote! {
+        // Each value cast to the type, along with the index of its variant and whether
+        // the conversions match it
+        const VALUES: [(#const_type, usize, bool); #value_count] = [#( #values ),*];
+
+        // The number of values the conversions match
+        const LEN: usize = {
+            let mut len = 0;
+            let mut i = 0;
+            while i < VALUES.len() {
+                if VALUES[i].2 {
+                    len += 1;
+                }
+                i += 1;
+            }
+            len
+        };
+
+        // The matched values, insertion-sorted by their keys. The sort is stable, so the
+        // first variant claiming a value comes first, and wins like in the match.
+        const ENTRIES: [(#const_type, usize); LEN] = {
+            let mut entries = [(0, 0); LEN];
+            let mut len = 0;
+            let mut i = 0;
+            while i < VALUES.len() {
+                if VALUES[i].2 {
+                    let mut j = len;
+                    while j > 0 && entries[j - 1].0 > VALUES[i].0 {
+                        entries[j] = entries[j - 1];
+                        j -= 1;
+                    }
+                    entries[j] = (VALUES[i].0, VALUES[i].1);
+                    len += 1;
+                }
+                i += 1;
+            }
+            entries
+        };
+
+        // Find the first entry whose key isn't below the value
+        let key = #match_value;
+        let mut low = 0;
+        let mut high = LEN;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if ENTRIES[middle].0 < key {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        let index = if low < LEN && ENTRIES[low].0 == key {
+            Some(ENTRIES[low].1)
+        } else {
+            None
+        };
+    }
+}
+
+// Returns the entries of the perfect hash map of the `phf` strategy: each value matched by
+// the conversions from the type, cast to it as a suffixed literal, along with the index of
+// the variant it converts to. Like in the match, the first variant claiming a value wins.
+// Returns `None` for the types whose width the macro doesn't know, and for `bool`, whose
+// values aren't integers.
+fn generate_phf_entries(
+    variants: &[&Variant],
+    conversion_type: &ConversionType,
+    options: &Context,
+) -> Option<Vec<(TokenStream, usize)>> {
+    if conversion_type.name == "bool" {
+        return None;
+    }
+    let mut values = Vec::new();
+    let mut aliases = Vec::new();
+    for (index, variant) in variants.iter().enumerate() {
+        let (value, variant_aliases) = literal_values(variant, options)?;
+        values.push((index, value));
+        aliases.extend(variant_aliases.into_iter().map(|alias| (index, alias)));
+    }
+
+    let mut claimed = BTreeSet::new();
+    let mut entries = Vec::new();
+    for (index, value) in values.into_iter().chain(aliases) {
+        let cast = conversion_type.cast_literal(value)?;
+        if !conversion_type.matches_literal(value, cast, options.strict) || !claimed.insert(cast) {
+            continue;
+        }
+        // `phf_map!` needs the keys to be literals of the key type
+        entries.push((conversion_type.literal(cast), index));
+    }
+    Some(entries)
+}
+
+// Generates the body of `from_u8` for the table strategy, which indexes into a table
+// spanning every `u8` instead of matching. The table is filled in reverse declaration
+// order so that, like in the match, the first declared variant wins when two
+// discriminants collide.
+fn generate_table_body(name: &Ident, variants: &[&Variant], options: &Context) -> TokenStream {
+    let table_entries = variants.iter().rev().map(|variant| {
+        let variant_name = &variant.ident;
+        let raw_value = raw_value(name, variant, options);
+        quote! {
+            table[#raw_value as i128 as u8 as usize] = Some(#name::#variant_name);
+        }
+    });
+    // Let the trace hook (if any) observe unmatched values
+    let trace_call = options.trace.as_ref().map(|trace| {
+        quote! {
+            if result.is_none() {
+                #trace(value);
+            }
+        }
+    });
+    quote! {
+        const NONE: Option<#name> = None;
+        // The table spans every `u8`, so indexing it can't go out of bounds, and the
+        // lookup needs neither a bounds check nor a branch
+        static TABLE: [Option<#name>; 256] = {
+            let mut table = [NONE; 256];
+            #( #table_entries )*
+            table
+        };
+
+        let result = TABLE[value as usize];
+        #trace_call
+        result
+    }
+}
+
+// Generates the name-based reverse lookup, matching the variant identifier strings.
+//
+// Rather than comparing the input against every name in turn, the names are bucketed
+// by their length and then by their first byte, so only the few names that share both
+// are compared in full. This keeps lookups fast for enums with many variants.
+fn generate_name_lookup(variants: &[&Variant], options: &Options) -> syn::Result<TokenStream> {
+    if cfg!(feature = "phf") && options.phf {
+        return Ok(generate_phf_name_lookup(variants));
+    }
+
+    // Variant names grouped by length, then by first byte (lowercased when matching
+    // case-insensitively). Identifiers are never empty.
+    let mut buckets: BTreeMap<usize, BTreeMap<u8, Vec<&Variant>>> = BTreeMap::new();
+    for (i, variant) in variants.iter().enumerate() {
+        let variant_str = variant_str(variant);
+        let mut first_byte = variant_str.as_bytes()[0];
+        if options.case_insensitive {
+            first_byte = first_byte.to_ascii_lowercase();
+
+            // Ensure every name can be told apart from the others
+            if let Some(other) = variants[..i]
+                .iter()
+                .find(|other| variant_str.eq_ignore_ascii_case(&self::variant_str(other)))
+            {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "`{}` only differs from `{}` by case, so they can't be told apart by `case_insensitive`",
+                        variant_str,
+                        self::variant_str(other)
+                    ),
+                ));
+            }
+        }
+        buckets
+            .entry(variant_str.len())
+            .or_default()
+            .entry(first_byte)
+            .or_default()
+            .push(variant);
+    }
+
+    let first_byte = if options.case_insensitive {
+        quote! { bytes[0].to_ascii_lowercase() }
+    } else {
+        quote! { bytes[0] }
+    };
+    let length_arms = buckets.into_iter().map(|(len, first_byte_buckets)| {
+        let first_byte_arms = first_byte_buckets
+            .into_iter()
+            .map(|(first_byte, variants)| {
+                let lookup = if options.case_insensitive {
+                    // Compare without allocating a lowercased copy of the name
+                    let name_checks = variants.iter().map(|variant| {
+                        let variant_name = &variant.ident;
+                        let variant_str = variant_str(variant);
+                        quote! {
+                            if name.eq_ignore_ascii_case(#variant_str) {
+                                return Some(Self::#variant_name);
+                            }
+                        }
+                    });
+                    quote! {
+                        {
+                            #( #name_checks )*
+                            None
+                        }
+                    }
+                } else {
+                    let name_arms = variants.iter().map(|variant| {
+                        let variant_name = &variant.ident;
+                        let variant_str = variant_str(variant);
+                        quote! {
+                            #variant_str => Some(Self::#variant_name),
+                        }
+                    });
+                    quote! {
+                        match name {
+                            #( #name_arms )*
+                            _ => None,
+                        }
+                    }
+                };
+                quote! {
+                    #first_byte => #lookup,
+                }
+            });
+        quote! {
+            #len => match #first_byte {
+                #( #first_byte_arms )*
+                _ => None,
+            },
+        }
+    });
+
+    let docs = if options.case_insensitive {
+        quote! {
+            /// Returns `Some(variant)` if `name` is the name of a variant, ignoring
+            /// ASCII case. Returns `None` otherwise.
+        }
+    } else {
+        quote! {
+            /// Returns `Some(variant)` if `name` is exactly the name of a variant.
+            /// Returns `None` otherwise.
+        }
+    };
+
+    Ok(quote! {
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Looks up a variant by its identifier.
+        ///
+        #docs
+        pub fn variant_for_name(name: &str) -> Option<Self> {
+            let bytes = name.as_bytes();
+            match bytes.len() {
+                #( #length_arms )*
+                _ => None,
+            }
+        }
+    })
+}
+
+// Generates the name-based reverse lookup backed by a `phf` perfect hash map, for the
+// `phf` option. The map holds declaration indices rather than variants, so the enum
+// doesn't have to be `Copy`.
+fn generate_phf_name_lookup(variants: &[&Variant]) -> TokenStream {
+    let map_entries = variants.iter().enumerate().map(|(index, variant)| {
+        let variant_str = variant_str(variant);
+        quote! { #variant_str => #index, }
+    });
+    let index_arms = variants.iter().enumerate().map(|(index, variant)| {
+        let variant_name = &variant.ident;
+        quote! { Some(#index) => Some(Self::#variant_name), }
+    });
+
+    quote! {
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Looks up a variant by its identifier, using a perfect hash map.
+        ///
+        /// Returns `Some(variant)` if `name` is exactly the name of a variant.
+        /// Returns `None` otherwise.
+        pub fn variant_for_name(name: &str) -> Option<Self> {
+            static NAME_MAP: ::phf::Map<&'static str, usize> = ::phf::phf_map! {
+                #( #map_entries )*
+            };
+            match NAME_MAP.get(name).copied() {
+                #( #index_arms )*
+                _ => None,
+            }
+        }
+    }
+}
+
+// Generates the `<Enum>Iter` iterator over all variants in declaration order, along
+// with the `iter()` method constructing it. Variants are constructed by the iterator
+// rather than copied, so the enum doesn't have to be `Copy`.
+fn generate_iter(input: &DeriveInput, variants: &[&Variant]) -> (TokenStream, TokenStream) {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let iter_name = format_ident!("{}Iter", name.unraw());
+    let variant_count = variants.len();
+    let index_arms = variants.iter().enumerate().map(|(index, variant)| {
+        let variant_name = &variant.ident;
+        quote! {
+            #index => #name::#variant_name,
+        }
+    });
+    let iter_doc = format!(
+        " An iterator over all variants of [`{}`], in declaration order.",
+        name.unraw()
+    );
+    let variant_names = variants.iter().map(|variant| &variant.ident);
+
+    let method = quote! {
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        /// All variants, in declaration order.
+        pub const VARIANTS: [Self; #variant_count] = [#( Self::#variant_names ),*];
+
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns an iterator over all variants, in declaration order.
+        pub fn iter() -> #iter_name {
+            #iter_name { index: 0 }
+        }
+    };
+
+    let items = quote! {
+        #[doc = #iter_doc]
+        #[derive(Debug, Clone)]
+        #[allow(dead_code)]
+        #vis struct #iter_name {
+            // The declaration index of the next variant to yield
+            index: usize,
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::Iterator for #iter_name {
+            type Item = #name;
+
+            fn next(&mut self) -> Option<#name> {
+                let variant = match self.index {
+                    #( #index_arms )*
+                    _ => return None,
+                };
+                self.index += 1;
+                Some(variant)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = #variant_count - self.index.min(#variant_count);
+                (remaining, Some(remaining))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::ExactSizeIterator for #iter_name {}
+
+        #[automatically_derived]
+        impl ::core::iter::FusedIterator for #iter_name {}
+    };
+
+    (method, items)
+}
+
+// Generates the `<Enum>ParseError` error type and a `TryFrom` impl for each type. The
+// error tells values outside the range of the discriminants (when cast to the type)
+// apart from values that fall in a gap between them. The range is computed at
+// const-eval time, so it works for any discriminant expression.
+fn generate_try_from(
+    input: &DeriveInput,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let error_name = format_ident!("{}ParseError", name.unraw());
+    let error_doc = format!(
+        " The error returned when converting a raw integer value to [`{}`] fails.",
+        name.unraw()
+    );
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let mut impls = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        let specified_type = &conversion_type.ty;
+        let const_type = conversion_type.const_type();
+        let match_value = conversion_type.match_value();
+        let fn_name = conversion_type.method_name("from_", "");
+        let returns_self = conversion_type.returns_self(options);
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+        let body = if returns_self {
+            quote! { Ok(#functions::#fn_name(value)) }
+        } else if variants.is_empty() {
+            // Without any variants, there's no range for a value to fall in
+            quote! { Err(#error_name::OutOfRange(value)) }
+        } else {
+            // The aliases are matched too, so they extend the range
+            let discriminants: Vec<TokenStream> = variants
+                .iter()
+                .flat_map(|variant| matched_values(name, variant, options))
+                .map(|value| quote! { #value as i128 as #const_type })
+                .collect();
+            let variant_count = discriminants.len();
+            quote! {
+                const DISCRIMINANTS: [#const_type; #variant_count] = [#( #discriminants ),*];
+                // The smallest and largest discriminants, computed at const-eval time
+                const RANGE: (#const_type, #const_type) = {
+                    let mut min = DISCRIMINANTS[0];
+                    let mut max = DISCRIMINANTS[0];
+                    let mut i = 1;
+                    while i < DISCRIMINANTS.len() {
+                        if DISCRIMINANTS[i] < min {
+                            min = DISCRIMINANTS[i];
+                        }
+                        if DISCRIMINANTS[i] > max {
+                            max = DISCRIMINANTS[i];
+                        }
+                        i += 1;
+                    }
+                    (min, max)
+                };
+
+                // Check the bounds first, then look for a variant in the range
+                if #match_value < RANGE.0 || #match_value > RANGE.1 {
+                    return Err(#error_name::OutOfRange(value));
+                }
+                #functions::#fn_name(value).ok_or(#error_name::NoVariant(value))
+            }
+        };
+
+        impls.push(quote! {
+            #cfg_attr
+            #[automatically_derived]
+            impl ::core::convert::TryFrom<#specified_type> for #name {
+                type Error = #error_name<#specified_type>;
+
+                fn try_from(value: #specified_type) -> Result<Self, Self::Error> {
+                    #body
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(dead_code)]
+        #vis enum #error_name<T> {
+            /// The value is outside the range of the discriminants.
+            OutOfRange(T),
+            /// The value is within the range of the discriminants, but matches no variant.
+            NoVariant(T),
+        }
+
+        #[automatically_derived]
+        impl<T: ::core::fmt::Display> ::core::fmt::Display for #error_name<T> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::OutOfRange(value) => {
+                        write!(f, "value {} is outside the range of the discriminants", value)
+                    }
+                    Self::NoVariant(value) => write!(f, "value {} matches no variant", value),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl<T: ::core::fmt::Debug + ::core::fmt::Display> ::core::error::Error for #error_name<T> {}
+
+        #( #impls )*
+    })
+}
+
+// Generates the generic `from_raw` method, which converts a value of any requested type
+// through the `FromRaw` impls, for callers that want one name regardless of the type
+fn generate_generic_from_raw(options: &Options) -> TokenStream {
+    let must_use_attr = must_use_attr(options);
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Converts a raw integer value of any of the requested types to the variant
+        /// with the matching discriminant (when cast to the type).
+        ///
+        /// Returns `None` if `value` doesn't match any variant.
+        pub fn from_raw<T: ::rawenum_core::RawInt>(value: T) -> Option<Self>
+        where
+            Self: ::rawenum_core::FromRaw<T>,
+        {
+            <Self as ::rawenum_core::FromRaw<T>>::from_raw(value)
+        }
+    }
+}
+
+// Generates the `FromRaw` and `IntoRaw` impls of `rawenum-core` for each type, going
+// through the `from_*` and `to_*` methods. `bool` has no `to_*` method, so it only gets
+// `FromRaw`.
+fn generate_raw_traits(input: &DeriveInput, options: &Context) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let mut impls = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        let specified_type = &conversion_type.ty;
+        let from_fn_name = conversion_type.checked_from_name(options);
+        let to_fn_name = conversion_type.method_name("to_", "");
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+        // The trait always returns an `Option`, even for conversions that can't fail
+        let from_body = if conversion_type.never_fails(options) {
+            quote! { Some(#functions::#from_fn_name(value)) }
+        } else {
+            quote! { #functions::#from_fn_name(value) }
+        };
+
+        impls.push(quote! {
+            #cfg_attr
+            #[automatically_derived]
+            impl ::rawenum_core::FromRaw<#specified_type> for #name {
+                fn from_raw(value: #specified_type) -> Option<Self> {
+                    #from_body
+                }
+            }
+        });
+
+        if conversion_type.name != "bool" {
+            impls.push(quote! {
+                #cfg_attr
+                #[automatically_derived]
+                impl ::rawenum_core::IntoRaw<#specified_type> for #name {
+                    fn into_raw(self) -> #specified_type {
+                        #functions::#to_fn_name(&self)
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(quote! { #( #impls )* })
+}
+
+// Generates a const assertion ensuring that the type can exactly represent each of the
+// given values of the variants, failing with the message returned for the first variant
+// whose value it can't. It is evaluated by the compiler, so it works for any discriminant
+// expression.
+fn generate_fit_assertion(
+    values: &[(&Variant, TokenStream)],
+    target: &TargetType,
+    conversion_type: &ConversionType,
+    message: impl Fn(&Variant) -> String,
+) -> TokenStream {
+    let checks = values.iter().map(|(variant, raw_value)| {
+        let fits = conversion_type.fits(raw_value);
+        let message = message(variant);
+        let cfgs = variant_cfgs(variant);
+        quote! {
+            #cfgs
+            assert!(#fits, #message);
+        }
+    });
+    let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+    quote! {
+        #cfg_attr
+        const _: () = {
+            #( #checks )*
+        };
+    }
+}
+
+// Generates the const assertions of the `deny_lossy` option, which ensure that each type
+// can exactly represent the value of every variant, along with its aliases, rather than
+// truncating it when casting it
+fn generate_deny_lossy(
+    name: &Ident,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let values: Vec<(&Variant, TokenStream)> = variants
+        .iter()
+        .map(|variant| (*variant, raw_value(name, variant, options)))
+        .chain(variants.iter().flat_map(|variant| {
+            alias_values(variant)
+                .into_iter()
+                .map(|(alias, _)| (*variant, alias.to_token_stream()))
+        }))
+        .collect();
+
+    let mut assertions = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        assertions.push(generate_fit_assertion(
+            &values,
+            target,
+            &conversion_type,
+            |variant| {
+                format!(
+                    "a value of `{}` doesn't fit in {}, and the `deny_lossy` option forbids truncating it",
+                    variant_str(variant),
+                    conversion_type.name
+                )
+            },
+        ));
+    }
+    Ok(quote! { #( #assertions )* })
+}
+
+// Generates `impl From<Enum> for <type>` for each type except `bool`, going through the
+// `to_*` methods. Unless the `lossy` option is given, const assertions ensure that the
+// type holds the value of every variant, so that `into()` never truncates. They are
+// evaluated by the compiler, so they work for any discriminant expression.
+fn generate_from_enum_impls(
+    input: &DeriveInput,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let mut impls = Vec::new();
+    for target in &options.types {
+        let conversion_type = ConversionType::new(&target.ty, options)?;
+        // A discriminant can't be cast to `bool`
+        if conversion_type.name == "bool" {
+            continue;
+        }
+        let specified_type = &conversion_type.ty;
+        let fn_name = conversion_type.method_name("to_", "");
+        let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+        // The `deny_lossy` option already checks every value
+        let assertions = (!options.lossy && !options.deny_lossy).then(|| {
+            let values: Vec<(&Variant, TokenStream)> = variants
+                .iter()
+                .map(|variant| (*variant, raw_value(name, variant, options)))
+                .collect();
+            generate_fit_assertion(&values, target, &conversion_type, |variant| {
+                format!(
+                    "the value of `{}` doesn't fit in {}, pass the `lossy` option to allow truncating it",
+                    variant_str(variant),
+                    conversion_type.name
+                )
+            })
+        });
+
+        impls.push(quote! {
+            #assertions
+
+            #cfg_attr
+            #[automatically_derived]
+            impl ::core::convert::From<#name> for #specified_type {
+                fn from(value: #name) -> Self {
+                    #functions::#fn_name(&value)
+                }
+            }
+        });
+    }
+
+    Ok(quote! { #( #impls )* })
+}
+
+// Generates the `Serialize` and `Deserialize` impls, which represent the enum as its raw
+// value of the first requested type. They only refer to `serde` under the user's
+// `serde` feature, so rawenum doesn't need a dependency on it, and neither does a crate
+// with the feature off.
+fn generate_serde(
+    input: &DeriveInput,
+    variants: &[&Variant],
+    options: &Context,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let target = &options.types[0];
+    let conversion_type = ConversionType::new(&target.ty, options)?;
+    let specified_type = &conversion_type.ty;
+    // Untrusted input must never panic, so unknown values go through a conversion that
+    // returns `None` for them, even with `total`
+    let fn_name = conversion_type.checked_from_name(options);
+    let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let serialize_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let raw_value = raw_value(name, variant, options);
+        let value = if conversion_type.name == "bool" {
+            quote! { (#raw_value as i128) != 0 }
+        } else {
+            quote! { #raw_value as i128 as #specified_type }
+        };
+        let cfgs = variant_cfgs(variant);
+        quote! { #cfgs #name::#variant_name => #value, }
+    });
+
+    let message = format!(
+        "invalid raw {} value for `{}`: {{}}",
+        conversion_type.name,
+        name.unraw()
+    );
+    let deserialize_body = if conversion_type.never_fails(options) {
+        quote! { Ok(#functions::#fn_name(value)) }
+    } else {
+        quote! {
+            #functions::#fn_name(value).ok_or_else(|| {
+                <D::Error as ::serde::de::Error>::custom(::core::format_args!(#message, value))
+            })
+        }
+    };
+
+    Ok(quote! {
+        #[cfg(feature = "serde")]
+        #cfg_attr
+        #[automatically_derived]
+        impl ::serde::Serialize for #name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let value: #specified_type = match self {
+                    #( #serialize_arms )*
+                };
+                ::serde::Serialize::serialize(&value, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #cfg_attr
+        #[automatically_derived]
+        impl<'de> ::serde::Deserialize<'de> for #name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <#specified_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                #deserialize_body
+            }
+        }
+    })
+}
+
+// Generates the API that `num_enum`'s `TryFromPrimitive` and `IntoPrimitive` derives
+// give the enum, for the type of its `#[repr(...)]`: the `NAME` const, the
+// `try_from_primitive` function failing with an `<Enum>TryFromPrimitiveError`, and the
+// `TryFrom` and `From` impls, so call sites don't change when switching to rawenum.
+fn generate_num_enum(input: &DeriveInput, options: &Context) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let repr = primitive_repr(&input.attrs).expect("the representation is checked by `generate`");
+    let target = options
+        .types
+        .iter()
+        .find(|target| type_ident(&target.ty) == Some(&repr))
+        .expect("the representation is added to the types by `generate`");
+    let conversion_type = ConversionType::new(&target.ty, options)?;
+    let from_fn_name = conversion_type.checked_from_name(options);
+    let to_fn_name = conversion_type.method_name("to_", "");
+    let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+    let error_name = format_ident!("{}TryFromPrimitiveError", name.unraw());
+    let error_doc = format!(
+        " The error returned when a primitive value matches no variant of [`{}`].",
+        name.unraw()
+    );
+    let enum_name = name.unraw().to_string();
+    let message = format!(
+        "No discriminant in enum `{}` matches the value `{{:?}}`",
+        name.unraw()
+    );
+
+    // The conversions live either on the enum or in the module given by the `module` option
+    let functions = match &options.module {
+        Some(module) => quote! { #module },
+        None => quote! { #name },
+    };
+
+    let body = if conversion_type.never_fails(options) {
+        quote! { Ok(#functions::#from_fn_name(number)) }
+    } else {
+        quote! { #functions::#from_fn_name(number).ok_or(#error_name { number }) }
+    };
+
+    Ok(quote! {
+        #[doc = #error_doc]
+        #cfg_attr
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #error_name {
+            /// The value that matched no variant.
+            pub number: #repr,
+        }
+
+        #cfg_attr
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, #message, self.number)
+            }
+        }
+
+        #cfg_attr
+        #[automatically_derived]
+        impl ::core::error::Error for #error_name {}
+
+        #cfg_attr
+        impl #name {
+            /// The name of the enum.
+            #[allow(dead_code)]
+            pub const NAME: &'static str = #enum_name;
+
+            /// Converts a primitive value to the variant with the matching discriminant.
+            ///
+            /// Returns an error holding `number` if it doesn't match any variant.
+            #[allow(dead_code)]
+            pub fn try_from_primitive(number: #repr) -> Result<Self, #error_name> {
+                #body
+            }
+        }
+
+        #cfg_attr
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<#repr> for #name {
+            type Error = #error_name;
+
+            fn try_from(number: #repr) -> Result<Self, Self::Error> {
+                Self::try_from_primitive(number)
+            }
+        }
+
+        #cfg_attr
+        #[automatically_derived]
+        impl ::core::convert::From<#name> for #repr {
+            fn from(enum_value: #name) -> Self {
+                #functions::#to_fn_name(&enum_value)
+            }
+        }
+    })
+}
+
+// Generates the `num_traits::FromPrimitive` and `ToPrimitive` impls. Like those derived
+// by `num_derive`, they compare the full values rather than values cast to a type, so
+// `from_i64(-1)` doesn't match a variant with the value 255. They only refer to
+// `num_traits` under the user's `num-traits` feature, like the `serde` impls.
+fn generate_num_traits(
+    input: &DeriveInput,
+    all_variants: &[&Variant],
+    variants: &[&Variant],
+    options: &Context,
+) -> TokenStream {
+    let name = &input.ident;
+
+    // The aliases are matched too, like in the `from_*` methods
+    let from_checks = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let cfgs = variant_cfgs(variant);
+        let values = matched_values(name, variant, options);
+        quote! {
+            #cfgs
+            if #( value == #values as i128 )||* {
+                return Some(#name::#variant_name);
+            }
+        }
+    });
+    // Unmatched values convert to the catch-all variant, if one is marked
+    let unmatched = match &options.catch_all {
+        Some(catch_all) => quote! { Some(#name::#catch_all) },
+        None => quote! { None },
+    };
+
+    let to_arms = all_variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let raw_value = raw_value(name, variant, options);
+        let cfgs = variant_cfgs(variant);
+        quote! { #cfgs #name::#variant_name => #raw_value as i128, }
+    });
+
+    quote! {
+        #[cfg(feature = "num-traits")]
+        impl #name {
+            // The value of a variant, which the `ToPrimitive` methods narrow to their type
+            #[doc(hidden)]
+            fn __rawenum_to_i128(&self) -> i128 {
+                match self {
+                    #( #to_arms )*
+                }
+            }
+
+            // The variant with a value, which the `FromPrimitive` methods widen from their type
+            #[doc(hidden)]
+            fn __rawenum_from_i128(value: i128) -> Option<Self> {
+                #( #from_checks )*
+                #unmatched
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        #[automatically_derived]
+        impl ::num_traits::FromPrimitive for #name {
+            fn from_i64(value: i64) -> Option<Self> {
+                Self::__rawenum_from_i128(value.into())
+            }
+
+            fn from_u64(value: u64) -> Option<Self> {
+                Self::__rawenum_from_i128(value.into())
+            }
+
+            fn from_i128(value: i128) -> Option<Self> {
+                Self::__rawenum_from_i128(value)
+            }
+
+            fn from_u128(value: u128) -> Option<Self> {
+                Self::__rawenum_from_i128(::core::convert::TryFrom::try_from(value).ok()?)
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        #[automatically_derived]
+        impl ::num_traits::ToPrimitive for #name {
+            fn to_i64(&self) -> Option<i64> {
+                ::core::convert::TryFrom::try_from(self.__rawenum_to_i128()).ok()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                ::core::convert::TryFrom::try_from(self.__rawenum_to_i128()).ok()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                Some(self.__rawenum_to_i128())
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                ::core::convert::TryFrom::try_from(self.__rawenum_to_i128()).ok()
+            }
+        }
+    }
+}
+
+// Generates the `RAWENUM_PRIMARY` const, naming the first requested type, for macros
+// layered on top of this one. It is gated like the methods of that type.
+fn generate_primary_const(options: &Options) -> syn::Result<TokenStream> {
+    let target = &options.types[0];
+    let conversion_type = ConversionType::new(&target.ty, options)?;
+    let type_name = &conversion_type.name;
+    let cfg_attr = target.cfg.as_ref().map(|cfg| quote! { #[#cfg] });
+
+    Ok(quote! {
+        #cfg_attr
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        /// The name of the primary integer type of the enum, which is the first type
+        /// its conversions were generated for.
+        pub const RAWENUM_PRIMARY: &'static str = #type_name;
+    })
+}
+
+// Generates `from_index`, which maps a declaration-order index back to its variant,
+// regardless of the discriminants
+fn generate_from_index(variants: &[&Variant]) -> TokenStream {
+    let index_arms = variants.iter().enumerate().map(|(index, variant)| {
+        let variant_name = &variant.ident;
+        quote! {
+            #index => Some(Self::#variant_name),
+        }
+    });
+
+    quote! {
+        #[must_use]
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns the variant declared at the given index, counting from 0.
+        ///
+        /// Returns `None` if the index is not less than the number of variants.
+        pub const fn from_index(index: usize) -> Option<Self> {
+            match index {
+                #( #index_arms )*
+                _ => None,
+            }
+        }
+    }
+}
+
+// Generates `to_ordinal`, which maps a variant to its declaration-order index, and the
+// `ORDINALS` array listing every ordinal, for tables indexed by variant. The ordinals are
+// contiguous regardless of the discriminants.
+fn generate_ordinals(variants: &[&Variant]) -> TokenStream {
+    let variant_count = variants.len();
+    let ordinal_arms = variants.iter().enumerate().map(|(ordinal, variant)| {
+        let variant_name = &variant.ident;
+        quote! {
+            Self::#variant_name => #ordinal,
+        }
+    });
+    let ordinals = 0..variant_count;
+
+    quote! {
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        /// The ordinal of every variant, in declaration order: `0` up to the number
+        /// of variants, exclusive.
+        pub const ORDINALS: [usize; #variant_count] = [#( #ordinals ),*];
+
+        #[must_use]
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns the index at which the variant is declared, counting from 0.
+        ///
+        /// Unlike the discriminants, the ordinals are always contiguous, so they can
+        /// index an array with an entry per variant.
+        pub const fn to_ordinal(&self) -> usize {
+            match *self {
+                #( #ordinal_arms )*
+            }
+        }
+    }
+}
+
+// Generates the `MIN_DISCRIMINANT` and `MAX_DISCRIMINANT` consts, with the given suffix,
+// holding the smallest and the largest of the values, which are expressions of the given
+// type. The values are compared by the compiler, so any discriminant expression is
+// supported. An enum without variants has neither.
+fn generate_discriminant_range(
+    values: Vec<TokenStream>,
+    ty: &impl ToTokens,
+    suffix: &str,
+) -> Option<TokenStream> {
+    if values.is_empty() {
+        return None;
+    }
+    let min_name = format_ident!("MIN_DISCRIMINANT{}", suffix);
+    let max_name = format_ident!("MAX_DISCRIMINANT{}", suffix);
+    let ty = ty.to_token_stream();
+    let type_str = ty.to_string();
+    let min_doc = format!(" The smallest value of a variant, cast to `{}`.", type_str);
+    let max_doc = format!(" The largest value of a variant, cast to `{}`.", type_str);
+
+    Some(quote! {
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        #[doc = #min_doc]
+        pub const #min_name: #ty = {
+            let values = [#( #values ),*];
+            let mut min = values[0];
+            let mut i = 1;
+            while i < values.len() {
+                if values[i] < min {
+                    min = values[i];
+                }
+                i += 1;
+            }
+            min
+        };
+
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        #[doc = #max_doc]
+        pub const #max_name: #ty = {
+            let values = [#( #values ),*];
+            let mut max = values[0];
+            let mut i = 1;
+            while i < values.len() {
+                if values[i] > max {
+                    max = values[i];
+                }
+                i += 1;
+            }
+            max
+        };
+    })
+}
+
+// Generates the `variant_name` method, returning the name of the variant without going
+// through `Debug`, so that it works without the formatting machinery
+fn generate_variant_name(variants: &[&Variant], options: &Options) -> TokenStream {
+    let must_use_attr = must_use_attr(options);
+    let name_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let variant_str = variant_str(variant);
+        let cfgs = variant_cfgs(variant);
+        // The braces match the variants of any kind
+        quote! {
+            #cfgs
+            Self::#variant_name { .. } => #variant_str,
+        }
+    });
+
+    quote! {
+        #must_use_attr
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Returns the name of the variant, as written in the enum definition.
+        pub const fn variant_name(&self) -> &'static str {
+            match self {
+                #( #name_arms )*
+            }
+        }
+    }
+}
+
+// Generates the `VARIANT_COUNT` const. Each variant is counted under its `cfg` attributes,
+// so the count only covers the variants that are compiled in.
+fn generate_variant_count(variants: &[&Variant]) -> TokenStream {
+    let cfgs = variants.iter().map(|variant| variant_cfgs(variant));
+
+    quote! {
+        #[allow(dead_code)] // Allow this const to be unused without a warning
+        /// The number of variants of the enum.
+        pub const VARIANT_COUNT: usize = {
+            let mut count = 0;
+            #(
+                #cfgs
+                {
+                    count += 1;
+                }
+            )*
+            count
+        };
+    }
+}
+
+// Generates the comparison by discriminant value rather than by declaration order
+fn generate_cmp_by_discriminant(variants: &[&Variant]) -> TokenStream {
+    let discriminant_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let cfgs = variant_cfgs(variant);
+        quote! {
+            #cfgs
+            Self::#variant_name => Self::#variant_name as i128,
+        }
+    });
+    quote! {
+        #[allow(dead_code)] // Allow this function to be unused without a warning
+        /// Compares two variants by their discriminant values.
+        ///
+        /// Unlike a derived `Ord`, which orders variants by declaration order,
+        /// this orders them by their numeric discriminants. The discriminants are
+        /// compared as `i128`, so the full range of both signed and unsigned
+        /// 64-bit discriminants is ordered correctly.
+        pub fn cmp_by_discriminant(&self, other: &Self) -> ::core::cmp::Ordering {
+            let discriminant = |value: &Self| -> i128 {
+                match value {
+                    #( #discriminant_arms )*
+                }
+            };
+            discriminant(self).cmp(&discriminant(other))
+        }
+    }
+}
+
+// Extracts the note of a `#[deprecated]` attribute, given either as
+// `#[deprecated = "note"]` or as `#[deprecated(note = "note")]`
+fn deprecation_note(attr: &Attribute) -> Option<String> {
+    match &attr.meta {
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(note),
+                    ..
+                }),
+            ..
+        }) => Some(note.value()),
+        Meta::List(_) => {
+            let mut note = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("note") {
+                    note = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.input.peek(Token![=]) {
+                    // Skip other keys such as `since`
+                    meta.value()?.parse::<Lit>()?;
+                }
+                Ok(())
+            })
+            .ok()?;
+            note
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expands the macro for the given attribute arguments and item into a string
+    fn expand(attr: TokenStream, item: TokenStream) -> String {
+        let options: Options = syn::parse2(attr).unwrap();
+        let input: DeriveInput = syn::parse2(item).unwrap();
+        generate(&input, &options).unwrap().to_string()
+    }
+
+    #[test]
+    fn impl_is_automatically_derived() {
+        let expanded = expand(
+            quote! { u8 },
+            quote! {
+                enum MyEnum {
+                    A = 1,
+                }
+            },
+        );
+        assert!(expanded.contains("# [automatically_derived] impl MyEnum"));
+    }
+
+    #[test]
+    fn discriminant_consts_are_declared_once_per_type() {
+        let expanded = expand(
+            quote! { i32, u8, named },
+            quote! {
+                enum MyEnum {
+                    A = BASE,
+                    B,
+                    C,
+                }
+            },
+        );
+        // One const per variant and type, shared by `from_*` and `from_*_named`, rather
+        // than one per variant and method (which would be 12 here)
+        assert_eq!(expanded.matches("const __RAWENUM_DISCRIMINANT_").count(), 6);
+    }
+
+    #[test]
+    fn literal_discriminants_are_matched_by_literals() {
+        let expanded = expand(
+            quote! { i8, u16 },
+            quote! {
+                enum MyEnum {
+                    A = -1,
+                    B = 5,
+                    #[rawenum(alias = 6, alias = 7)]
+                    C = 8,
+                }
+            },
+        );
+        assert!(!expanded.contains("const __RAWENUM_DISCRIMINANT_"));
+        assert!(expanded.contains("- 1i8 => Some (Self :: A)"));
+        assert!(expanded.contains("65535u16 => Some (Self :: A)"));
+        // The aliases and the discriminant of `C` follow each other
+        assert!(expanded.contains("6i8 ..= 8i8 => Some (Self :: C)"));
+    }
+
+    #[test]
+    fn negative_aliases_are_merged_into_ranges() {
+        let expanded = expand(
+            quote! { i8 },
+            quote! {
+                enum MyEnum {
+                    #[rawenum(alias = -3, alias = -4)]
+                    A = -2,
+                    #[rawenum(alias = 1)]
+                    B = 3,
+                }
+            },
+        );
+        assert!(expanded.contains("- 4i8 ..= - 2i8 => Some (Self :: A)"));
+        // The values of `B` don't follow each other
+        assert!(expanded.contains("1i8 => Some (Self :: B)"));
+        assert!(expanded.contains("3i8 => Some (Self :: B)"));
+    }
+
+    #[test]
+    fn u128_values_above_i128_max_are_matched_by_literals() {
+        let expanded = expand(
+            quote! { u128 },
+            quote! {
+                enum MyEnum {
+                    A = 1,
+                    #[rawenum(alias = -1)]
+                    B = -2,
+                }
+            },
+        );
+        assert!(expanded.contains("1u128 => Some (Self :: A)"));
+        // Negative values wrap above `i128::MAX`, where they aren't merged into a range
+        assert!(expanded.contains(&format!("{}u128 => Some (Self :: B)", u128::MAX - 1)));
+        assert!(expanded.contains(&format!("{}u128 => Some (Self :: B)", u128::MAX)));
+    }
+
+    #[test]
+    fn bool_values_are_matched_by_u8_literals() {
+        let expanded = expand(
+            quote! { bool },
+            quote! {
+                enum MyEnum {
+                    Off = 0,
+                    On = 1,
+                }
+            },
+        );
+        assert!(!expanded.contains("const __RAWENUM_DISCRIMINANT_"));
+        assert!(expanded.contains("0u8 => Some (Self :: Off)"));
+        assert!(expanded.contains("1u8 => Some (Self :: On)"));
+    }
+
+    #[test]
+    fn cfg_gated_variants_fall_back_to_consts() {
+        let expanded = expand(
+            quote! { u8 },
+            quote! {
+                enum MyEnum {
+                    A = 1,
+                    #[cfg(any())]
+                    B = 2,
+                }
+            },
+        );
+        // The arm of `B` must carry its `cfg`, which the literal arms don't
+        assert!(expanded.contains("const __RAWENUM_DISCRIMINANT_"));
+        assert!(!expanded.contains("1u8 => Some (Self :: A)"));
+    }
+
+    #[test]
+    fn screaming_snake_case_names() {
+        assert_eq!(screaming_snake_case("VariantA"), "VARIANT_A");
+        assert_eq!(screaming_snake_case("HttpError"), "HTTP_ERROR");
+        assert_eq!(screaming_snake_case("HTTPError"), "HTTP_ERROR");
+        assert_eq!(screaming_snake_case("Ipv4Addr"), "IPV4_ADDR");
+        assert_eq!(screaming_snake_case("OK"), "OK");
+        assert_eq!(screaming_snake_case("Already_Snake"), "ALREADY_SNAKE");
+    }
+
+    #[test]
+    fn closest_type_suggestions() {
+        assert_eq!(edit_distance("u7", "u8"), 1);
+        assert_eq!(edit_distance("int", "i32"), 2);
+        assert_eq!(edit_distance("", "u8"), 2);
+        assert_eq!(closest_type("u7"), Some("u8"));
+        assert_eq!(closest_type("c_uit"), Some("c_uint"));
+        assert_eq!(edit_distance("i23", "i32"), 1);
+        assert_eq!(closest_type("i23"), Some("i32"));
+        assert_eq!(closest_type("u6"), Some("u64"));
+        assert_eq!(closest_type("int"), Some("c_int"));
+        assert_eq!(closest_type("uint32_t"), Some("u32"));
+        assert_eq!(closest_type("String"), None);
+    }
+
+    #[test]
+    fn catch_all_payload_widths() {
+        assert!(holds_losslessly("u32", "u8"));
+        assert!(holds_losslessly("i32", "u16"));
+        assert!(holds_losslessly("u8", "bool"));
+        assert!(holds_losslessly("c_long", "c_int"));
+        assert!(!holds_losslessly("u16", "u32"));
+        assert!(!holds_losslessly("i16", "u16"));
+        assert!(!holds_losslessly("u64", "i8"));
+        assert!(!holds_losslessly("i32", "isize"));
+        assert!(!holds_losslessly("u8", "c_char"));
+    }
+
+    #[test]
+    fn wire_values_accept_all_literal_bases() {
+        let wire = |attr: TokenStream| {
+            let variant: Variant = parse_quote! { #attr A };
+            wire_value(&variant).unwrap().map(|(_, value)| value)
+        };
+        assert_eq!(wire(quote! { #[rawenum(wire = 0xFF)] }), Some(255));
+        assert_eq!(wire(quote! { #[rawenum(wire = 0b1010)] }), Some(10));
+        assert_eq!(wire(quote! { #[rawenum(wire = 0o17)] }), Some(15));
+        assert_eq!(wire(quote! { #[rawenum(wire = 1_000)] }), Some(1000));
+        assert_eq!(wire(quote! { #[rawenum(wire = 0x7Fu8)] }), Some(127));
+        assert_eq!(wire(quote! { #[rawenum(wire = -0x10)] }), Some(-16));
+        assert_eq!(wire(quote! {}), None);
+    }
+
+    #[test]
+    fn impl_attached_form_extends_the_impl_block() {
+        let options: Options = syn::parse2(quote! { u8, variants(A, B) }).unwrap();
+        let item_impl: ItemImpl = syn::parse2(quote! {
+            impl MyEnum {
+                fn hand_written(&self) {}
+            }
+        })
+        .unwrap();
+        let expanded = generate_impl(&item_impl, &options).unwrap().to_string();
+        // A single impl block holds both the hand-written and the generated methods
+        assert_eq!(expanded.matches("impl MyEnum").count(), 1);
+        assert!(expanded.contains("fn hand_written"));
+        assert!(expanded.contains("pub fn from_u8"));
+    }
+
+    // Expands an enum with the given number of variants
+    fn expand_with_variants(attr: TokenStream, variant_count: usize) -> String {
+        let variants = (0..variant_count).map(|i| format_ident!("V{}", i));
+        expand(attr, quote! { enum MyEnum { #( #variants, )* } })
+    }
+
+    #[test]
+    fn narrow_types_share_the_match_of_large_enums() {
+        let expanded = expand_with_variants(quote! { u16, i32, u64, named, contains }, 300);
+        assert!(expanded.contains("Self :: from_u64 (value as u64)"));
+        assert!(expanded.contains("Self :: from_u64_named (value as u64)"));
+        assert!(expanded.contains("Self :: contains_u64 (value as u64)"));
+        assert!(!expanded.contains("299u16"));
+        // `u64` can't hold the negative values of `i32`, which keeps its own match
+        assert!(expanded.contains("299i32"));
+        assert!(expanded.contains("299u64"));
+
+        let expanded = expand_with_variants(quote! { u16, u64 }, 255);
+        assert!(!expanded.contains("Self :: from_u64"));
+    }
+
+    #[test]
+    fn auto_inline_small_enum() {
+        let expanded = expand_with_variants(quote! { u8, i32, auto_inline }, 7);
+        assert_eq!(expanded.matches("# [inline]").count(), 2);
+    }
+
+    #[test]
+    fn auto_inline_large_enum() {
+        let expanded = expand_with_variants(quote! { u8, i32, auto_inline }, 8);
+        assert!(!expanded.contains("# [inline]"));
+    }
+
+    #[test]
+    fn auto_inline_custom_threshold() {
+        let expanded = expand_with_variants(quote! { u8, auto_inline(16) }, 15);
+        assert!(expanded.contains("# [inline]"));
+        let expanded = expand_with_variants(quote! { u8, auto_inline(16) }, 16);
+        assert!(!expanded.contains("# [inline]"));
+    }
+
+    #[test]
+    fn no_inline_without_auto_inline() {
+        let expanded = expand_with_variants(quote! { u8 }, 1);
+        assert!(!expanded.contains("# [inline]"));
+    }
+}
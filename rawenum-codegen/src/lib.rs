@@ -0,0 +1,7 @@
+//! The code generation of the `rawenum` macro, shared by the macro and by
+//! `rawenum-build`, so that the code generated from build scripts is exactly what the
+//! macro would expand to. The options are parsed from the macro's arguments, and
+//! [`codegen::generate`] turns them and an enum into its conversions.
+
+pub mod codegen;
+pub mod options;
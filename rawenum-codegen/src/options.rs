@@ -0,0 +1,550 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use syn::{
+    DeriveInput, Expr, Ident, LitInt, LitStr, Meta, Path, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token,
+};
+
+// The variant count below which `auto_inline` marks the `from_*` methods `#[inline]`,
+// unless overridden with `auto_inline(N)`
+const DEFAULT_AUTO_INLINE_THRESHOLD: usize = 8;
+
+// The name scheme of the consts generated by a bare `consts`, e.g. `VARIANT_A_U8`
+const DEFAULT_CONSTS_SCHEME: &str = "{VARIANT}_{TYPE}";
+
+// The integer types requested by the `signed` and `unsigned` group keywords, which `all`
+// requests together
+const SIGNED_TYPES: &[&str] = &["i8", "i16", "i32", "i64"];
+const UNSIGNED_TYPES: &[&str] = &["u8", "u16", "u32", "u64"];
+
+// Helper struct to parse the attribute arguments (the specified types and options)
+#[derive(Default, Clone)]
+pub struct Options {
+    pub types: Vec<TargetType>,
+    // Whether the macro is the `RawEnum` derive, whose input is emitted by the compiler
+    // rather than by the macro. It is set by the derive rather than from the attribute.
+    pub derive: bool,
+    // Whether the enum's `#[repr(...)]` type is added to the types
+    pub repr: bool,
+    // Whether to generate the `variant_for_name` reverse lookup function
+    pub name_lookup: bool,
+    // Whether to generate the `cmp_by_discriminant` comparison method
+    pub ord_by_value: bool,
+    // Whether `from_u8` should index into a lookup table instead of matching
+    pub table: bool,
+    // Whether to omit `#[must_use]` from the generated conversion methods
+    pub no_must_use: bool,
+    // Whether the 8-bit conversions are infallible, covering every value of their type
+    pub exhaustive: bool,
+    // Whether to generate the infallible `from_u8_exhaustive` and `from_i8_exhaustive`
+    pub exhaustive_methods: bool,
+    // Whether the conversions return `Self`, panicking on invalid values
+    pub total: bool,
+    // Whether the `from_*` methods are `const fn`, given as `const`
+    pub const_fn: bool,
+    // Whether variants only match types that can exactly represent their values
+    pub strict: bool,
+    // Whether to generate the wrapping `from_*_lossy` methods alongside the strict ones
+    pub lossy_methods: bool,
+    // Whether to generate the `from_*_unchecked` methods
+    pub unchecked: bool,
+    // Whether to reject types that can't losslessly hold the value of every variant
+    pub strict_types: bool,
+    // Whether to reject, by const assertions, values that a type can't exactly represent
+    pub deny_lossy: bool,
+    // Whether to reject variants whose values collide once cast to a type
+    pub deny_collisions: bool,
+    // The variants of the enum, listed when applying the macro to an impl block
+    pub variants: Option<Vec<Ident>>,
+    // Whether to generate the `from_*_named` methods, which also return the variant name
+    pub named: bool,
+    // The variants the conversions are restricted to, if `only(...)` was given
+    pub only: Option<Vec<Ident>>,
+    // Whether to generate a test asserting that every variant round-trips
+    pub roundtrip_test: bool,
+    // Whether to generate a test checking the conversions against every value of the small types
+    pub property_test: bool,
+    // Whether to generate the `to_*_saturating` methods
+    pub saturating: bool,
+    // Whether to generate the `assert_distinct_*` const fns
+    pub assert_distinct: bool,
+    // Whether to generate the `RAWENUM_PRIMARY` const naming the first type
+    pub primary: bool,
+    // Whether to generate the `from_index` positional lookup
+    pub from_index: bool,
+    // Whether to generate the `<Enum>Iter` iterator and the `iter()` method
+    pub iter: bool,
+    // Whether to generate the `from_any_*` and `any_matches_*` slice helpers
+    pub slice_helpers: bool,
+    // Whether to generate the panicking `expect_*` methods
+    pub expect: bool,
+    // Whether to generate the `from_*_flow` methods returning `ControlFlow`
+    pub control_flow: bool,
+    // Whether to generate the `from_opt_*` methods
+    pub opt_helpers: bool,
+    // Whether to generate the `try_from_*` methods returning a `Result`
+    pub result: bool,
+    // The error type of the `try_from_*` methods, if `error = ...` was given
+    pub error: Option<Type>,
+    // The function constructing the error, if `error_fn = ...` was given, rather than `From`
+    pub error_fn: Option<Path>,
+    // Whether to implement the `FromRaw` and `IntoRaw` traits of `rawenum-core`
+    pub traits: bool,
+    // Whether to generate the API of `num_enum`'s `TryFromPrimitive` and `IntoPrimitive`
+    pub num_enum: bool,
+    // Whether to implement `num_traits::FromPrimitive` and `ToPrimitive`
+    pub num_traits: bool,
+    // Whether to generate the generic `from_raw` method, which is backed by the traits
+    pub generic: bool,
+    // Whether to generate the `from_nonzero_*` methods
+    pub nonzero: bool,
+    // Whether to generate the `const fn contains_*` predicates
+    pub contains: bool,
+    // Whether to implement `Serialize` and `Deserialize` under the `serde` feature
+    pub serde: bool,
+    // Whether to generate `to_ordinal` and the `ORDINALS` array
+    pub ordinals: bool,
+    // Whether to generate the `VARIANT_COUNT` const
+    pub variant_count: bool,
+    // Whether to generate the `variant_name` method
+    pub variant_name: bool,
+    // Whether to generate the `name_of_*` lookups of the variant names by value
+    pub name_of: bool,
+    // Whether to generate the `MIN_DISCRIMINANT` and `MAX_DISCRIMINANT` consts
+    pub discriminant_range: bool,
+    // The name scheme of the public associated consts holding the value of each variant
+    // cast to each type, if `consts` or `consts = "..."` was given
+    pub consts: Option<LitStr>,
+    // Whether to implement `From<Enum>` for each type
+    pub impl_from: bool,
+    // Whether the `From<Enum>` impls may truncate the values of the variants
+    pub lossy: bool,
+    // Whether to generate the `sorted_entries_*` methods
+    pub sorted_entries: bool,
+    // Whether to generate the `DISCRIMINANTS_*` consts of values and names
+    pub discriminants: bool,
+    // Whether `variant_for_name` uses a `phf` perfect hash map, when the `phf` feature is on
+    pub phf: bool,
+    // Whether `variant_for_name` ignores ASCII case
+    pub case_insensitive: bool,
+    // Whether to generate `TryFrom` impls along with the `<Enum>ParseError` error type
+    pub try_from: bool,
+    // The variant count below which the `from_*` methods are marked `#[inline]`, if
+    // `auto_inline` was given
+    pub auto_inline: Option<usize>,
+    // The hook called with unmatched values in the `from_*` methods, if `trace = ...` was given
+    pub trace: Option<Path>,
+    // The module to generate free functions in instead of methods, if `module = ...` was given
+    pub module: Option<Ident>,
+    // The tag field to decode from packed words, if `decode(...)` was given
+    pub decode: Option<Decode>,
+    // The file the variants are read from, relative to `CARGO_MANIFEST_DIR`, if
+    // `values_from = "..."` was given
+    pub values_from: Option<LitStr>,
+    // Appended to the type in the generated method names, if `suffix = "..."` was given
+    pub suffix: Option<LitStr>,
+    // How the `from_*` methods find the variant matching a value, given by `strategy = "..."`
+    pub strategy: Strategy,
+}
+
+// How the `from_*` methods find the variant matching a value
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    // A match with an arm per value
+    #[default]
+    Match,
+    // A perfect hash map built at compile time, for large sparse enums
+    Phf,
+    // A binary search over the values sorted at compile time, for medium-sized sparse enums
+    BinarySearch,
+    // Picked by the macro from the number of values and their density
+    Auto,
+}
+
+// The position of the tag field in a packed word, for the `decode_*` methods
+#[derive(Clone)]
+pub struct Decode {
+    // The offset of the tag field, in bits
+    pub shift: u32,
+    // The mask of the tag field, applied after shifting
+    pub mask: u128,
+    // The span of the option, for the error when the field doesn't fit in any type
+    pub span: Span,
+}
+
+// A single requested integer type, along with the `cfg` predicate (if any) that
+// gates the methods generated for it
+#[derive(Clone)]
+pub struct TargetType {
+    pub ty: Type,
+    pub cfg: Option<Meta>,
+}
+
+impl Parse for Options {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Options::default();
+        // The most recently seen `cfg(...)` group, applied to all types that follow it
+        let mut cfg: Option<Meta> = None;
+        // Parse a comma-separated list of types and options
+        while !input.is_empty() {
+            if input.peek(Token![use]) {
+                // `split_type_list` removes the first one before parsing
+                return Err(input.error("only one `use` type list is allowed"));
+            } else if input.peek(Token![const]) {
+                // A keyword, so it can't be parsed as a type like the other flags
+                input.parse::<Token![const]>()?;
+                options.const_fn = true;
+            } else if input.peek(Ident) && input.peek2(token::Paren) {
+                let meta: Meta = input.parse()?;
+                if meta.path().is_ident("cfg") {
+                    cfg = Some(meta);
+                } else if meta.path().is_ident("types") {
+                    // The derive's way of listing the types, e.g. `types(u8, i32)`
+                    let types = meta
+                        .require_list()?
+                        .parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+                    options.types.extend(types.into_iter().map(|ty| TargetType {
+                        ty,
+                        cfg: cfg.clone(),
+                    }));
+                } else if meta.path().is_ident("only") {
+                    let variants = meta
+                        .require_list()?
+                        .parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                    options.only = Some(variants.into_iter().collect());
+                } else if meta.path().is_ident("variants") {
+                    let variants = meta
+                        .require_list()?
+                        .parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                    options.variants = Some(variants.into_iter().collect());
+                } else if meta.path().is_ident("decode") {
+                    let mut shift = None;
+                    let mut mask = None;
+                    meta.require_list()?.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("shift") {
+                            shift = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                        } else if meta.path.is_ident("mask") {
+                            mask = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                        } else {
+                            return Err(meta.error("expected `shift = ...` or `mask = ...`"));
+                        }
+                        Ok(())
+                    })?;
+                    let (Some(shift), Some(mask)) = (shift, mask) else {
+                        return Err(syn::Error::new_spanned(
+                            meta,
+                            "both `shift` and `mask` are required, e.g. `decode(shift = 4, mask = 0xF)`",
+                        ));
+                    };
+                    options.decode = Some(Decode {
+                        shift,
+                        mask,
+                        span: meta.span(),
+                    });
+                } else if meta.path().is_ident("auto_inline") {
+                    let threshold: LitInt = meta.require_list()?.parse_args()?;
+                    options.auto_inline = Some(threshold.base10_parse()?);
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        meta.path(),
+                        "unknown option, expected `cfg(...)`, `types(...)`, `only(...)`, `variants(...)`, `decode(...)`, `auto_inline(...)` or an integer type",
+                    ));
+                }
+            } else if input.peek(Ident) && input.peek2(Token![=]) {
+                // `key = value` options
+                let key: Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                if key == "trace" {
+                    options.trace = Some(input.parse()?);
+                } else if key == "error" {
+                    // A custom error only applies to the `try_from_*` methods
+                    options.error = Some(input.parse()?);
+                    options.result = true;
+                } else if key == "error_fn" {
+                    options.error_fn = Some(input.parse()?);
+                } else if key == "strategy" {
+                    let strategy: LitStr = input.parse()?;
+                    options.strategy = match strategy.value().as_str() {
+                        "match" => Strategy::Match,
+                        "phf" => Strategy::Phf,
+                        "binary_search" => Strategy::BinarySearch,
+                        "auto" => Strategy::Auto,
+                        // The lookup table is the `table` option under another name
+                        "table" => {
+                            options.table = true;
+                            Strategy::Match
+                        }
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                strategy,
+                                "unknown strategy, expected \"match\", \"table\", \"binary_search\", \"phf\" or \"auto\"",
+                            ));
+                        }
+                    };
+                } else if key == "consts" {
+                    let scheme: LitStr = input.parse()?;
+                    validate_consts_scheme(&scheme)?;
+                    options.consts = Some(scheme);
+                } else if key == "values_from" {
+                    options.values_from = Some(input.parse()?);
+                } else if key == "module" {
+                    options.module = Some(input.parse()?);
+                } else if key == "suffix" {
+                    let suffix: LitStr = input.parse()?;
+                    // The suffix becomes part of the method names
+                    let value = suffix.value();
+                    if value.is_empty()
+                        || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    {
+                        return Err(syn::Error::new_spanned(
+                            suffix,
+                            "the suffix must be made of ASCII letters, digits and underscores",
+                        ));
+                    }
+                    options.suffix = Some(suffix);
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        key,
+                        "unknown option, expected `trace = path::to::fn`, `error = Type`, `error_fn = path::to::fn`, `strategy = \"...\"`, `consts = \"...\"`, `values_from = \"...\"`, `module = name` or `suffix = \"...\"`",
+                    ));
+                }
+            } else {
+                // Bare identifiers are either option flags or integer types
+                let ty: Type = input.parse()?;
+                match type_ident(&ty).map(|ident| ident.to_string()).as_deref() {
+                    Some("name_lookup") => options.name_lookup = true,
+                    Some("ord_by_value") => options.ord_by_value = true,
+                    Some("table") => options.table = true,
+                    Some("no_must_use") => options.no_must_use = true,
+                    Some("exhaustive") => options.exhaustive = true,
+                    Some("exhaustive_methods") => options.exhaustive_methods = true,
+                    Some("total") => options.total = true,
+                    Some("strict_types") => options.strict_types = true,
+                    Some("deny_collisions") => options.deny_collisions = true,
+                    Some("deny_lossy") => options.deny_lossy = true,
+                    Some("strict") => options.strict = true,
+                    Some("lossy_methods") => {
+                        options.lossy_methods = true;
+                        options.strict = true;
+                    }
+                    Some("repr") => options.repr = true,
+                    Some("named") => options.named = true,
+                    Some("roundtrip_test") => options.roundtrip_test = true,
+                    Some("property_test") => options.property_test = true,
+                    Some("saturating") => options.saturating = true,
+                    Some("assert_distinct") => options.assert_distinct = true,
+                    Some("iter") => options.iter = true,
+                    Some("from_index") => options.from_index = true,
+                    Some("primary") => options.primary = true,
+                    Some("slice_helpers") => options.slice_helpers = true,
+                    Some("try_from" | "impl_try_from") => options.try_from = true,
+                    Some("case_insensitive") => options.case_insensitive = true,
+                    Some("phf") => options.phf = true,
+                    Some("sorted_entries") => options.sorted_entries = true,
+                    Some("discriminants") => options.discriminants = true,
+                    Some("opt_helpers") => options.opt_helpers = true,
+                    Some("nonzero") => options.nonzero = true,
+                    Some("traits") => options.traits = true,
+                    Some("num_traits") => options.num_traits = true,
+                    Some("num_enum") => options.num_enum = true,
+                    Some("generic") => {
+                        options.generic = true;
+                        options.traits = true;
+                    }
+                    Some("result") => options.result = true,
+                    Some("unchecked") => options.unchecked = true,
+                    Some("contains") => options.contains = true,
+                    Some("serde") => options.serde = true,
+                    Some("ordinals") => options.ordinals = true,
+                    Some("variant_count") => options.variant_count = true,
+                    Some("variant_name") => options.variant_name = true,
+                    Some("name_of") => options.name_of = true,
+                    Some("discriminant_range") => options.discriminant_range = true,
+                    Some("consts") => {
+                        options.consts = Some(LitStr::new(DEFAULT_CONSTS_SCHEME, ty.span()))
+                    }
+                    Some("impl_from") => options.impl_from = true,
+                    Some("lossy") => options.lossy = true,
+                    Some("expect") => options.expect = true,
+                    Some("control_flow") => options.control_flow = true,
+                    Some("auto_inline") => {
+                        options.auto_inline = Some(DEFAULT_AUTO_INLINE_THRESHOLD)
+                    }
+                    Some(group @ ("all" | "signed" | "unsigned")) => {
+                        let names = match group {
+                            "signed" => SIGNED_TYPES.to_vec(),
+                            "unsigned" => UNSIGNED_TYPES.to_vec(),
+                            _ => [UNSIGNED_TYPES, SIGNED_TYPES].concat(),
+                        };
+                        // Types that were already requested aren't requested again
+                        for name in names {
+                            if options.types.iter().any(|target| {
+                                type_ident(&target.ty).is_some_and(|ident| ident == name)
+                            }) {
+                                continue;
+                            }
+                            let ident = Ident::new(name, ty.span());
+                            options.types.push(TargetType {
+                                ty: parse_quote!(#ident),
+                                cfg: cfg.clone(),
+                            });
+                        }
+                    }
+                    _ => options.types.push(TargetType {
+                        ty,
+                        cfg: cfg.clone(),
+                    }),
+                }
+            }
+            // If there's more input, expect a comma
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(options)
+    }
+}
+
+// Ensures that a `consts = "..."` name scheme names a distinct const for each variant, and
+// that the names it produces are identifiers
+fn validate_consts_scheme(scheme: &LitStr) -> syn::Result<()> {
+    let value = scheme.value();
+    if !value.contains("{VARIANT}") {
+        return Err(syn::Error::new_spanned(
+            scheme,
+            "the name scheme must contain `{VARIANT}`, e.g. `consts = \"{VARIANT}_{TYPE}\"`",
+        ));
+    }
+    let rest = value.replace("{VARIANT}", "").replace("{TYPE}", "");
+    if value.starts_with(|c: char| c.is_ascii_digit())
+        || !rest.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(syn::Error::new_spanned(
+            scheme,
+            "the name scheme must be made of ASCII letters, digits, underscores, `{VARIANT}` and `{TYPE}`, and can't start with a digit",
+        ));
+    }
+    Ok(())
+}
+
+// Returns the identifier of a type if it is a plain, single-segment path (e.g. `u8`)
+pub fn type_ident(ty: &Type) -> Option<&Ident> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident(),
+        _ => None,
+    }
+}
+
+// Splits a `use TypeList` entry, referencing a type list defined by `rawenum_types!`,
+// off the attribute arguments. Returns the path of the type list along with the
+// remaining arguments.
+pub fn split_type_list(attr: TokenStream) -> (Option<TokenStream>, TokenStream) {
+    // Split the arguments into the comma-separated entries, keeping groups whole
+    let mut entries: Vec<Vec<TokenTree>> = vec![Vec::new()];
+    for token in attr {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => entries.push(Vec::new()),
+            _ => entries.last_mut().unwrap().push(token),
+        }
+    }
+
+    let position = entries
+        .iter()
+        .position(|entry| matches!(entry.first(), Some(TokenTree::Ident(ident)) if ident == "use"));
+    let type_list =
+        position.map(|position| entries.remove(position)[1..].iter().cloned().collect());
+
+    let mut remaining = TokenStream::new();
+    for entry in entries.into_iter().filter(|entry| !entry.is_empty()) {
+        remaining.extend(entry);
+        remaining.extend(quote::quote! { , });
+    }
+    (type_list, remaining)
+}
+
+// The input of `rawenum_types!`: the name of the type list, followed by the types (and
+// any other arguments) it stands for, e.g. `ProtocolTypes = i32, u8`
+pub struct TypeList {
+    pub name: Ident,
+    pub arguments: TokenStream,
+}
+
+impl Parse for TypeList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let arguments: TokenStream = input.parse()?;
+
+        // Report invalid arguments where the type list is defined rather than where it's used
+        let options: Options = syn::parse2(arguments.clone())?;
+        if options.types.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &name,
+                "at least one integer type must be specified, e.g., rawenum_types!(MyTypes = i32)",
+            ));
+        }
+        Ok(Self { name, arguments })
+    }
+}
+
+// The input of `rawenum_inline!`: an enum definition, optionally followed by `=>` and the
+// arguments of the attribute macro, e.g. `enum Opcode { Nop = 0x90 } => u8, named`
+pub struct InlineEnum {
+    pub input: DeriveInput,
+    pub arguments: TokenStream,
+}
+
+impl Parse for InlineEnum {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let item = input.parse()?;
+        // Without arguments, the type is read from the enum's `#[repr(...)]`
+        let arguments = if input.is_empty() {
+            TokenStream::new()
+        } else {
+            input.parse::<Token![=>]>()?;
+            input.parse()?
+        };
+        Ok(Self {
+            input: item,
+            arguments,
+        })
+    }
+}
+
+// The input of `rawenum_remote!`: a foreign enum, the types to convert from, and the
+// values of its variants, e.g. `for other_crate::Status as u32 { Ok = 0, Err = 1 }`
+pub struct RemoteEnum {
+    pub path: Path,
+    pub types: Vec<Type>,
+    pub variants: Vec<(Ident, Expr)>,
+}
+
+impl Parse for RemoteEnum {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![for]>()?;
+        let path = input.parse()?;
+        input.parse::<Token![as]>()?;
+        // The types are listed up to the braces holding the variants
+        let mut types = vec![input.parse()?];
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            types.push(input.parse()?);
+        }
+        let content;
+        syn::braced!(content in input);
+        let variants =
+            Punctuated::<(Ident, Expr), Token![,]>::parse_terminated_with(&content, |input| {
+                let name = input.parse()?;
+                input.parse::<Token![=]>()?;
+                Ok((name, input.parse()?))
+            })?;
+        Ok(Self {
+            path,
+            types,
+            variants: variants.into_iter().collect(),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+//! The traits implemented by enums using the `traits` option of the `rawenum` macro,
+//! for writing code that is generic over them rather than calling their inherent
+//! `from_<type>` and `to_<type>` methods by name.
+//!
+//! ```ignore
+//! use rawenum::rawenum;
+//! use rawenum_core::FromRaw;
+//!
+//! #[rawenum(u8, traits)]
+//! enum Opcode {
+//!     Nop = 0,
+//!     Halt = 1,
+//! }
+//!
+//! fn decode<E: FromRaw<u8>>(bytes: &[u8]) -> Option<E> {
+//!     E::from_raw(*bytes.first()?)
+//! }
+//! ```
+#![no_std]
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The raw types that enums using `rawenum` can be converted from: the primitive integer
+/// types, along with `bool`.
+///
+/// This is the bound of the generic `from_raw` method generated by the `generic` option.
+/// It is sealed, so it can't be implemented outside of this crate.
+pub trait RawInt: sealed::Sealed + Copy {}
+
+macro_rules! impl_raw_int {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl RawInt for $ty {}
+        )*
+    };
+}
+
+impl_raw_int!(
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, bool
+);
+
+/// Converts a raw integer value to the variant with the matching discriminant.
+///
+/// Implemented by the `traits` option of `rawenum` for each requested type, through the
+/// enum's `from_<type>` method.
+pub trait FromRaw<T>: Sized {
+    /// Returns the variant matching `value`, or `None` if no variant matches it.
+    fn from_raw(value: T) -> Option<Self>;
+}
+
+/// Converts a variant to its raw integer value.
+///
+/// Implemented by the `traits` option of `rawenum` for each requested type except `bool`,
+/// through the enum's `to_<type>` method.
+pub trait IntoRaw<T> {
+    /// Returns the raw value of the variant, cast to `T`.
+    fn into_raw(self) -> T;
+}
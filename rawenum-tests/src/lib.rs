@@ -0,0 +1,2 @@
+//! Tests of the `rawenum` options generating impls of the traits of other crates, which
+//! need those crates as dependencies, unlike the macro itself. The tests are in `tests`.
@@ -0,0 +1,28 @@
+use num_traits::{FromPrimitive, ToPrimitive};
+use rawenum::rawenum;
+
+#[rawenum(i16, num_traits)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    Trace = -1,
+    #[rawenum(alias = 10)]
+    Info = 1,
+    Fatal = 255,
+}
+
+#[test]
+fn test_level_num_traits() {
+    assert_eq!(Level::from_i64(-1), Some(Level::Trace));
+    // The inherent methods of the requested types take precedence over the trait's
+    assert_eq!(<Level as FromPrimitive>::from_i16(255), Some(Level::Fatal));
+    assert_eq!(Level::from_i32(10), Some(Level::Info));
+    // The full values are compared, without wrapping
+    assert_eq!(Level::from_i64(-255), None);
+    assert_eq!(Level::from_u64(u64::MAX), None);
+    assert_eq!(Level::from_u128(u128::MAX), None);
+
+    assert_eq!(Level::Trace.to_i8(), Some(-1));
+    assert_eq!(Level::Trace.to_u64(), None);
+    assert_eq!(ToPrimitive::to_u8(&Level::Fatal), Some(255));
+    assert_eq!(Level::Fatal.to_i8(), None);
+}
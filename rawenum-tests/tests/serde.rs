@@ -0,0 +1,53 @@
+use rawenum::rawenum;
+
+#[rawenum(u16, i64, serde)]
+#[derive(Debug, PartialEq)]
+enum Opcode {
+    Nop = 0,
+    Jump = 0x10,
+    Halt = 0xFFFF,
+}
+
+#[test]
+fn test_opcode_serde() {
+    // Serialized as the raw value of the first type
+    assert_eq!(serde_json::to_string(&Opcode::Jump).unwrap(), "16");
+    assert_eq!(serde_json::to_string(&Opcode::Halt).unwrap(), "65535");
+
+    assert_eq!(serde_json::from_str::<Opcode>("0").unwrap(), Opcode::Nop);
+    assert_eq!(
+        serde_json::from_str::<Vec<Opcode>>("[16, 65535]").unwrap(),
+        [Opcode::Jump, Opcode::Halt]
+    );
+
+    // Values that match no variant, or don't fit the type, are rejected
+    let error = serde_json::from_str::<Opcode>("1").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("invalid raw u16 value for `Opcode`: 1")
+    );
+    assert!(serde_json::from_str::<Opcode>("65536").is_err());
+}
+
+#[rawenum(u8, total, serde)]
+#[derive(Debug, PartialEq)]
+enum Priority {
+    Low = 1,
+    High = 2,
+}
+
+#[test]
+fn test_total_serde_rejects_unknown_values() {
+    assert_eq!(
+        serde_json::from_str::<Priority>("2").unwrap(),
+        Priority::High
+    );
+    // Unknown values are an error rather than a panic, even though `from_u8` panics
+    let error = serde_json::from_str::<Priority>("7").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("invalid raw u8 value for `Priority`: 7")
+    );
+}
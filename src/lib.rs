@@ -1,32 +1,9 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{
-    Data, DataEnum, DeriveInput, Token, Type,
-    parse::{Parse, ParseStream},
-    parse_macro_input,
-    spanned::Spanned,
-};
-
-// Helper struct to parse the attribute arguments (the specified types)
-struct AllowedTypes {
-    types: Vec<Type>,
-}
+use syn::{Attribute, DeriveInput, Ident, Item, ItemImpl, ItemMod, Meta, parse_macro_input};
 
-impl Parse for AllowedTypes {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        let mut types = Vec::new();
-        // Parse a comma-separated list of types
-        while !input.is_empty() {
-            let ty: Type = input.parse()?;
-            types.push(ty);
-            // If there's more input, expect a comma
-            if !input.is_empty() {
-                input.parse::<Token![,]>()?;
-            }
-        }
-        Ok(AllowedTypes { types })
-    }
-}
+use quote::quote;
+use rawenum_codegen::options::{InlineEnum, Options, RemoteEnum, TypeList};
+use rawenum_codegen::{codegen, options};
 
 /// A procedural macro to generate `from_*` methods for specific integer types
 /// for enums with explicit or implicit integer discriminants.
@@ -39,10 +16,13 @@ impl Parse for AllowedTypes {
 /// function takes a value of the corresponding integer type and returns an
 /// `Option<Self>`.
 ///
-/// Inside each `from_<type>` method, the macro generates a `const` for each
-/// enum variant. This constant holds the variant's discriminant value cast to
-/// the specific `<type>` of the method. The function then matches the input
-/// integer value against these locally defined constants.
+/// For each type, the macro generates a hidden associated `const` for each enum
+/// variant. This constant holds the variant's discriminant value cast to the
+/// `<type>`, going through `i128` so that the cast is always a two's-complement
+/// narrowing of the full discriminant value, regardless of the enum's `repr`.
+/// The `from_<type>` method then matches the input integer value against these
+/// constants, which are declared once per type and shared by all methods
+/// matching against them, keeping the expansion small for large enums.
 ///
 /// This correctly handles variants with implicit discriminants (starting from 0
 /// or the previous variant's value + 1).
@@ -52,6 +32,494 @@ impl Parse for AllowedTypes {
 /// Note that casting the discriminant to a smaller type might result in
 /// wrapping or truncation, which affects the values being matched against.
 ///
+/// The reverse conversions are generated too: `to_<type>(&self) -> type`
+/// (e.g. `to_u8`) is a `const fn` returning the variant's discriminant cast to
+/// the type, for encoding values without `as` casts. It is generated for every
+/// type except `bool`.
+///
+/// A variant can be matched by a different value than its discriminant by giving
+/// it a `#[rawenum(wire = <integer literal>)]` attribute, e.g. for protocol
+/// quirks where the on-wire value differs from the Rust discriminant. The
+/// conversions from raw values then match the wire value instead, and the
+/// `to_<type>` methods return it, while `variant as u8` still produces the
+/// discriminant. The literal can be written
+/// in any base, e.g. `0xFF`, `0b1010` or `0o17`. Two variants can't have the
+/// same wire value. `#[rawenum(value = <integer literal>)]` is accepted as
+/// another name for it, and also works for variants without an explicit
+/// discriminant.
+///
+/// A variant can be excluded from the conversions from raw values by giving it a
+/// `#[rawenum(skip)]` attribute, e.g. for internal sentinel variants that must
+/// never be constructed from untrusted input. It is never returned by the
+/// `from_<type>` methods and the methods built on them.
+///
+/// Variants can be gated behind `#[cfg(...)]` attributes (or `#[cfg_attr(...)]`
+/// attributes expanding to them), which are carried over to the consts and match
+/// arms generated for them, so the conversions only match the variants that are
+/// compiled in. The options that list the variants in arrays (`table`,
+/// `assert_distinct`, `sorted_entries`, `discriminants`, `discriminant_range`,
+/// `phf`, `try_from`, `property_test`, `iter`, `ordinals`, `from_index`,
+/// `name_lookup` and `strict_types`) can't be used with such variants.
+///
+/// Additional values can be matched to a variant with
+/// `#[rawenum(alias = <integer literal>)]`, which can be repeated, e.g.
+/// `#[rawenum(alias = 0x7F, alias = 0xFF)]` for legacy protocols where several
+/// codes mean the same thing. The aliases are only used when converting raw
+/// values, while `to_<type>` still returns the variant's own value, and they
+/// can't clash with another variant's wire values or aliases. They can't be used
+/// with the `table` option.
+///
+/// `bool` is also accepted as a pseudo-type, generating `from_bool`, which
+/// matches `false` to the variant with discriminant 0 and `true` to the variant
+/// with discriminant 1. Like the other types, discriminants are cast to `u8`
+/// before matching, so only discriminants 0 and 1 are meaningful.
+///
+/// `i128` and `u128` are supported too, including for `#[repr(u128)]` enums
+/// with discriminants above `i128::MAX`, which still match through the
+/// two's-complement cast. The checks of `strict`, `strict_types` and
+/// `impl_from` compare values as `i128` though, so they treat such values as
+/// out of range for `u128`, and `wire` values and aliases must fit in `i128`.
+///
+/// `usize` and `isize` are supported too, e.g. for enums used as array indices.
+/// Their width depends on the target, so casts to them truncate according to
+/// the target's pointer width rather than the host's, and the checks involving
+/// their range are done by the compiler instead of the macro.
+///
+/// The C type aliases from `core::ffi` (`c_char`, `c_schar`, `c_uchar`,
+/// `c_short`, `c_ushort`, `c_int`, `c_uint`, `c_long`, `c_ulong`, `c_longlong`
+/// and `c_ulonglong`) are also accepted, generating e.g. `from_c_int`. The
+/// generated code refers to them through `core::ffi`, so they don't have to be
+/// in scope, and casts through whatever primitive they alias on the target:
+/// `c_int` is `i32` on all common targets, `c_long` is `i64` on 64-bit Unix but
+/// `i32` on Windows, and `c_char` is `u8` on some targets (e.g. ARM Linux) and
+/// `i8` on others.
+///
+/// The `all` keyword requests all eight integer types at once, e.g.
+/// `#[rawenum(all)]`, and `signed` and `unsigned` request the four signed or
+/// unsigned ones. Types that are already requested aren't requested again, so
+/// they can be mixed with individual types.
+///
+/// Passing `repr` adds the type given by the enum's `#[repr(...)]` attribute to
+/// the requested types, e.g. generating `from_u8` for a `#[repr(u8)]` enum, which
+/// keeps the conversions in sync with the representation. This is also done when
+/// no types are given at all, so `#[rawenum]` on a `#[repr(u8)]` enum generates
+/// `from_u8`.
+///
+/// Passing `exhaustive` makes `from_u8` and `from_i8` return `Self` instead of
+/// `Option<Self>`, for enums whose discriminants (when cast to the type) cover
+/// every value of the type. The generated match has no catch-all arm, so the
+/// compiler rejects the enum if any value is left uncovered. Other types are
+/// unaffected.
+///
+/// Passing `exhaustive_methods` instead keeps `from_u8` and `from_i8` as they
+/// are, and additionally generates `from_u8_exhaustive` and `from_i8_exhaustive`,
+/// which return `Self` with the same compile-time proof of coverage, for hot
+/// loops such as instruction decoders. It can't be combined with `strict`. Use
+/// `exhaustive` when the enum is meant to cover every byte for good, so callers
+/// never handle `None`, and `exhaustive_methods` when the enum only happens to
+/// cover every byte today, so the usual `Option` signatures stay stable if a
+/// variant is later removed, while hot paths can opt into the infallible ones.
+///
+/// Passing `const` makes the `from_<type>` methods `const fn`, so raw values can
+/// be converted in const contexts, e.g. to build lookup tables at compile time.
+/// It can't be combined with `trace`, `table` or `total`.
+///
+/// Passing `total` makes every `from_<type>` method return `Self` instead of
+/// `Option<Self>`, for internal enums where an invalid value is a programmer
+/// error. Values that don't match any variant panic with
+/// `invalid discriminant <value>`, in both debug and release builds, and the
+/// methods are `#[track_caller]` so the panic points at the caller. With
+/// `exhaustive` as well, the 8-bit conversions still have no catch-all arm. The
+/// helpers that report invalid values, such as `from_<type>_flow`,
+/// `from_any_<type>`, `try_from_<type>`, `decode_<type>`, the `FromRaw` impls and
+/// `Deserialize`, still report them rather than panicking.
+///
+/// Passing `strict` makes a variant only match types that can exactly represent
+/// its value, instead of matching its value cast to the type with wrapping. For
+/// example, with `from_u8`, a variant with the discriminant 256 no longer matches
+/// 0, and is never returned by `from_u8`. It can't be combined with `exhaustive`
+/// or `table`.
+///
+/// Passing `deny_lossy` rejects, at compile time, any value of a variant or
+/// alias that a type can't exactly represent, so that e.g. `from_u8(251)` can
+/// never decode a variant whose discriminant is -5. Unlike `strict_types`, the
+/// values are checked by const assertions, so any discriminant expression is
+/// supported, but the error only names the variant. It can't be combined with
+/// `lossy`.
+///
+/// Two variants whose values become the same once cast to a type, e.g. 0 and
+/// 256 with `u8`, are accepted by default, with the first variant winning.
+/// Passing `deny_collisions` rejects them at compile time instead, since the
+/// conversions from the type could only ever return the first of them. The
+/// error names both variants when the discriminants are literals, and only the
+/// shadowed one when they are compared by a const assertion instead. Values
+/// that `strict` keeps from matching a type don't collide.
+///
+/// Passing `lossy_methods` implies `strict`, and additionally generates, for
+/// each type, `from_<type>_lossy`, which keeps matching the values cast to the
+/// type with wrapping, so call sites relying on the wrapping can be migrated to
+/// the strict `from_<type>` one at a time.
+///
+/// Passing `strict_types` rejects, at compile time, any type that can't
+/// losslessly hold the value of every variant, rather than silently truncating
+/// discriminants when casting them. This requires integer literal discriminants,
+/// which the macro evaluates along with the implicit ones; `wire` overrides are
+/// checked in place of the discriminant. The C type aliases, whose range depends
+/// on the target, are checked by const assertions instead.
+///
+/// A unit variant can be marked with `#[rawenum(catch_all)]`, e.g. an `Unknown`
+/// variant, making every `from_<type>` method return `Self` instead of
+/// `Option<Self>`: values that don't match any variant convert to the marked
+/// one. At most one variant can be marked, and it can't be combined with
+/// `total`, `table` or `property_test`.
+///
+/// The catch-all variant can also hold the unmatched value in a single field,
+/// e.g. `#[rawenum(catch_all)] Other(u32)`, so no information is lost when
+/// parsing forward-compatible protocols. The field must be an integer type
+/// holding every value of every type, e.g. `u32` with `u8` and `u32`, and
+/// `to_<type>` converts the variant back to the value it holds.
+/// Such an enum can't be cast to an integer, so it must have a primitive
+/// representation, e.g. `#[repr(u32)]`, through which the generated code reads
+/// the discriminants of the other variants with `unsafe` code. It can't be
+/// combined with `saturating`, `ord_by_value`, `slice_helpers`, `serde`,
+/// `num_traits`, `ordinals` or `strict_types`.
+///
+/// Passing `roundtrip_test` generates a `#[test]` function, only compiled under
+/// `#[cfg(test)]`, asserting that every variant round-trips through the
+/// `from_*` method of each type (except `bool`). This catches discriminants that
+/// collide or get truncated when cast to a type. The enum must implement `Debug`
+/// and `PartialEq`.
+///
+/// Passing `property_test` generates another `#[test]` function, only compiled
+/// under `#[cfg(test)]`, which checks every value of each of the types `i8`,
+/// `u8`, `i16` and `u16`: the `from_*` method must match the value if and only if
+/// it is one of the discriminants cast to the type. Larger types have too many
+/// values to check, so they are skipped, and at least one of these types must be
+/// requested.
+///
+/// Passing `trace = path::to::hook` calls the hook with every value that a
+/// `from_*` method fails to match, before it returns `None`. The hook only
+/// observes the value, so it must accept each requested type, e.g.
+/// `fn hook(value: i32)` for `#[rawenum(i32, trace = hook)]`.
+///
+/// Passing `module = name` generates everything in a module with the given name
+/// and the enum's visibility, instead of in an `impl` block, keeping the enum's
+/// namespace clean. Associated functions become free functions, e.g.
+/// `raw::from_u8(value)`, and methods take the enum as an explicit first
+/// argument, e.g. `raw::to_u8_saturating(&variant)`.
+///
+/// Passing `auto_inline` marks the `from_<type>` methods `#[inline]` when the
+/// enum has fewer than 8 variants, since inlining small matches pays off while
+/// inlining large ones bloats call sites. The threshold can be overridden, e.g.
+/// `#[rawenum(i32, auto_inline(16))]`.
+///
+/// Passing `suffix = "raw"` appends `_raw` to the type in the names of all
+/// generated methods, e.g. `from_u8_raw` and `from_u8_raw_named`, so they don't
+/// collide with hand-written constructors such as a `from_u8` taking a domain
+/// type.
+///
+/// The generated conversion methods are marked `#[must_use]`. Passing
+/// `no_must_use` omits the attribute for callers who intentionally discard the
+/// result.
+///
+/// The macro can also be applied to a newtype struct wrapping an enum that has
+/// the same conversions, e.g. `#[repr(transparent)] struct Tag(MyEnum);`. The
+/// generated `from_*` methods then delegate to the inner enum's methods and wrap
+/// the result.
+///
+/// The macro can also be applied to an existing inherent `impl` block of an
+/// enum, generating the methods into it alongside the hand-written ones instead
+/// of into a second `impl` block. The enum definition isn't visible from there,
+/// so its variants must be listed with `variants(...)`, e.g.
+/// `#[rawenum(u8, variants(A, B, C))] impl MyEnum { ... }`. Variant-level
+/// attributes such as `wire` aren't available in this form, and neither is the
+/// `module` option.
+///
+/// A `cfg(...)` group may appear in the type list to gate the methods of all
+/// types following it, e.g. `#[rawenum(u8, cfg(target_pointer_width = "64"), u64)]`
+/// only generates `from_u64` on 64-bit targets.
+///
+/// Passing `name_lookup` additionally generates
+/// `variant_for_name(name: &str) -> Option<Self>`, which looks a variant up by
+/// its identifier. The lookup first narrows the candidates down by the length
+/// and first byte of the name, so it stays fast for enums with many variants.
+/// Passing `case_insensitive` as well makes the lookup ignore ASCII case, e.g.
+/// matching `"varianta"` to `VariantA`, without allocating. Variants whose names
+/// only differ by case are rejected in this mode.
+///
+/// Passing `phf` as well makes `variant_for_name` consult a static `phf`
+/// perfect hash map instead, for O(1) lookups in enums with hundreds of
+/// variants. This requires enabling the `phf` feature of this crate, and a
+/// dependency on `phf` with its `macros` feature. With the feature off, the
+/// option falls back to the bucketed match.
+///
+/// Passing `strategy = "phf"` makes the `from_<type>` methods look values up in
+/// a static `phf` perfect hash map instead of matching them, for enums with
+/// thousands of sparse discriminants, such as USB vendor IDs. The macro builds
+/// the map from the values cast to each type, so the discriminants must be
+/// integer literals, and no variant can be gated behind a `cfg`. Like the `phf`
+/// option, this requires the `phf` feature of this crate and a dependency on
+/// `phf`, and falls back to the match with the feature off. Types whose width
+/// depends on the target, such as `usize`, and `bool` keep the match. It can't
+/// be combined with `const` or `table`. `strategy = "match"` is the default.
+///
+/// Passing `strategy = "binary_search"` makes the `from_<type>` methods binary
+/// search a sorted array of the values, each along with the index of its
+/// variant, for medium-sized sparse enums, keeping the code size bounded. The
+/// array is sorted by the compiler, so any discriminant expression is
+/// supported, and the methods can still be `const`. No variant can be gated
+/// behind a `cfg`, and it can't be combined with `table`.
+///
+/// `strategy = "table"` is the same as the `table` option. Passing
+/// `strategy = "auto"` lets the macro pick the strategy for each enum from its
+/// values: enums with fewer than 32 values, enums where at least every other
+/// value in the range matches a variant, and contiguous runs keep the match,
+/// which the compiler turns into a jump table or a range check. Larger sparse
+/// enums are binary searched, and those with 1024 values or more use `phf` when
+/// its feature is enabled and `const` isn't passed. Enums whose values the macro
+/// can't evaluate, such as those with non-literal discriminants or variants
+/// gated behind a `cfg`, keep the match.
+///
+/// For enums with 256 convertible variants or more, the values are only matched
+/// in the conversions from the widest requested primitive type. The
+/// `from_<type>`, `from_<type>_named` and `contains_<type>` methods of the
+/// narrower types that it holds call them instead, when both match the same
+/// variants: with `strict`, or when every value fits in the narrower type. This
+/// keeps the expansion from growing with the number of types. Types gated
+/// behind a `cfg`, the `table`, the exhaustive conversions, `lossy_methods` and
+/// `trace` keep a match per type.
+///
+/// Passing `only(A, B, ...)` restricts the integer conversions to the listed
+/// variants, so all other variants are never returned by them.
+///
+/// Passing `named` additionally generates a `from_<type>_named` method for each
+/// type, which returns the matched variant along with its name, e.g.
+/// `from_i32_named(value: i32) -> Option<(Self, &'static str)>`.
+///
+/// Passing `try_from` additionally generates a `TryFrom<type>` impl for each
+/// type, failing with a generated `<Enum>ParseError<type>` error. The error is
+/// `OutOfRange(value)` if the value is below the smallest or above the largest
+/// discriminant (when cast to the type), and `NoVariant(value)` if it falls in a
+/// gap between them. `impl_try_from` is accepted as another name for it.
+///
+/// Passing `result` additionally generates, for each type,
+/// `try_from_<type>(value: type) -> Result<Self, <Enum>InvalidRawValue<type>>`,
+/// e.g. `try_from_u8`, along with the generated `<Enum>InvalidRawValue<T>`
+/// error, which holds the value that matched no variant in its `value` field and
+/// implements `Display` and `Error`, so protocol errors can report the bad value.
+/// Its message names the enum and lists the values accepted by the type, e.g.
+/// `invalid raw value 7 for `MyEnum`, expected one of 1, 2`. The method is a
+/// `const fn` when `const` is passed.
+///
+/// Passing `error = MyError` makes the `try_from_<type>` methods return
+/// `Result<Self, MyError>` instead, converting the generated error with
+/// `From<<Enum>InvalidRawValue<type>>`, so call sites don't have to map it. It
+/// implies `result`. Passing `error_fn = path::to::constructor` as well builds
+/// the error by calling the constructor with the generated error instead of
+/// going through `From`. It can't be combined with `const`.
+///
+/// Passing `traits` additionally implements the `FromRaw<type>` and
+/// `IntoRaw<type>` traits of the companion `rawenum-core` crate for each type
+/// (only `FromRaw` for `bool`), going through `from_<type>` and `to_<type>`, so
+/// decoders can be generic over any enum using the macro, e.g.
+/// `fn decode<E: FromRaw<u8>>(byte: u8) -> Option<E>`. The crate using the
+/// option needs a dependency on `rawenum-core`.
+///
+/// Passing `generic` additionally generates
+/// `from_raw<T: RawInt>(value: T) -> Option<Self>`, which converts a value of
+/// any of the requested types, e.g. `MyEnum::from_raw(value)`, for macro-heavy
+/// callers that want one name regardless of the type. `RawInt` is a sealed
+/// trait of `rawenum-core` implemented for the integer types and `bool`, and
+/// the method goes through the `FromRaw` impls, so `generic` implies `traits`.
+///
+/// Passing `impl_from` additionally implements `From<Enum>` for each type except
+/// `bool`, converting through `to_<type>`, so encoders can write `value.into()`.
+/// The value of every variant must fit in each type, which is checked at compile
+/// time; passing `lossy` as well drops the check, letting the values be cast with
+/// truncation.
+///
+/// Passing `serde` additionally implements `serde::Serialize` and
+/// `serde::Deserialize`, representing the enum as its raw value of the first
+/// type. Deserializing a value that matches no variant fails with a custom
+/// error. The impls are gated behind `#[cfg(feature = "serde")]`, which is
+/// evaluated in the crate using the option, so rawenum doesn't depend on serde:
+/// the crate must declare a `serde` feature enabling its own optional `serde`
+/// dependency, e.g. `serde = ["dep:serde"]`, and the impls only exist while the
+/// feature is on.
+///
+/// Passing `num_enum` additionally generates the API that the
+/// `TryFromPrimitive` and `IntoPrimitive` derives of `num_enum` give an enum,
+/// for the type of its `#[repr(...)]` attribute, which is required and added
+/// to the types: `MyEnum::NAME`, `MyEnum::try_from_primitive(number)`,
+/// `TryFrom<repr>` and `From<MyEnum> for repr`. They fail with a generated
+/// `<Enum>TryFromPrimitiveError`, whose `number` field holds the value, so code
+/// written against `num_enum` keeps compiling unless it names
+/// `num_enum::TryFromPrimitiveError` or the `num_enum` traits themselves. The
+/// variant-level `num_enum` attributes aren't read, so `#[num_enum(default)]`
+/// and `#[num_enum(alternatives = [...])]` become `#[rawenum(catch_all)]` and
+/// `#[rawenum(alias = ...)]`. It can't be combined with `try_from` or
+/// `impl_from`.
+///
+/// Passing `num_traits` additionally implements `num_traits::FromPrimitive` and
+/// `num_traits::ToPrimitive`, for numeric code bounded on them. Like the impls
+/// derived by `num_derive`, they compare the full value of each variant rather
+/// than the value cast to a type, so `from_i64(-1)` doesn't match a variant with
+/// the value 255, and they ignore the requested types. The impls are gated
+/// behind `#[cfg(feature = "num-traits")]` in the crate using the option, like
+/// the `serde` impls. The inherent `from_<type>` and `to_<type>` methods take
+/// precedence over the trait methods with the same names, which can be called
+/// as e.g. `ToPrimitive::to_u8(&variant)`.
+///
+/// Passing `expect` additionally generates, for each type,
+/// `expect_<type>(value: type) -> Self`, which panics with the value if it
+/// doesn't match any variant. It is `#[track_caller]`, so the panic points at
+/// the call site.
+///
+/// Passing `control_flow` additionally generates, for each type,
+/// `from_<type>_flow(value: type) -> ControlFlow<type, Self>`, which returns
+/// `Continue(variant)` on a match and `Break(value)` otherwise, for parser loops
+/// driven by `ControlFlow`.
+///
+/// Passing `opt_helpers` additionally generates, for each type,
+/// `from_opt_<type>(value: Option<type>) -> Option<Self>`, which converts an
+/// optional raw value, e.g. one returned by `iter.next()`, without a closure.
+///
+/// Passing `nonzero` additionally generates, for each type except `bool`,
+/// `from_nonzero_<type>(value: NonZero<type>)`, e.g. `from_nonzero_u8`, which
+/// converts a `NonZeroU8` like `from_u8` does, so callers holding
+/// niche-optimized values don't have to call `get` first. It is a `const fn`
+/// when `const` is passed.
+///
+/// Passing `unchecked` additionally generates, for the type given by the enum's
+/// `#[repr(...)]` attribute, e.g. `u8` for `#[repr(u8)]`,
+/// `unsafe fn from_<type>_unchecked(value: type) -> Self`, which transmutes the
+/// value into the enum without matching it, for hot paths where the value is
+/// known to be valid. The caller must guarantee that the value is the
+/// discriminant of a variant, rather than a `wire` value or an alias. The
+/// representation must be one of the requested types, and the enum can't have
+/// a catch-all variant holding the raw value. It is a `const fn` when `const`
+/// is passed.
+///
+/// Passing `contains` additionally generates, for each type,
+/// `const fn contains_<type>(value: type) -> bool`, which checks whether a raw
+/// value matches any variant without converting it, e.g. in a match guard such
+/// as `v if MyEnum::contains_i32(v) => ...`, or in a const context.
+///
+/// Passing `decode(shift = <bits>, mask = <mask>)` additionally generates, for
+/// each integer type, `decode_<type>(word: type) -> Option<(Self, type)>`, for
+/// bit-packed formats where the enum is one field among several in a word. The
+/// tag field, `(word >> shift) & mask`, is matched against the discriminants, and
+/// the variant is returned along with the rest of the word, with the tag bits
+/// cleared. The method is skipped for the types too narrow for the tag field,
+/// e.g. `u8` with `decode(shift = 8, mask = 0xFF)`, and the widths of `isize`,
+/// `usize` and the C type aliases are taken as their smallest on any target. At
+/// least one type must hold the field.
+///
+/// Passing `slice_helpers` additionally generates, for each type,
+/// `from_any_<type>(values: &[type]) -> Option<Self>`, returning the variant
+/// matching the first value that matches any variant, and
+/// `any_matches_<type>(&self, values: &[type]) -> bool`, checking whether the
+/// variant's discriminant is among the values.
+///
+/// Passing `sorted_entries` additionally generates a
+/// `sorted_entries_<type>() -> &'static [(type, Self)]` function for each
+/// integer type, returning every variant along with its discriminant (when cast
+/// to the type), in ascending order of the discriminants. The entries are sorted
+/// by the compiler, so any discriminant expression is supported.
+///
+/// Passing `discriminants` additionally generates a
+/// `DISCRIMINANTS_<TYPE>: [(type, &'static str); N]` const for each integer
+/// type, e.g. `DISCRIMINANTS_U8`, listing the value of every variant (when cast
+/// to the type) along with its name, in declaration order. It lets tooling, debug
+/// dumps and help texts enumerate the valid values without a hand-maintained
+/// table.
+///
+/// Passing `saturating` additionally generates a `to_<type>_saturating(&self)`
+/// method for each integer type, which converts the variant's discriminant to
+/// the type, clamping it to the type's range instead of wrapping.
+///
+/// Passing `assert_distinct` additionally generates an
+/// `assert_distinct_<type>() -> bool` const fn for each type, which returns
+/// whether all discriminants are distinct when cast to the type. Since it is
+/// evaluated by the compiler, it works for any discriminant expression, and can
+/// be enforced with `const _: () = assert!(MyEnum::assert_distinct_u8());`.
+///
+/// Passing `iter` additionally generates an `<Enum>Iter` iterator type, which
+/// yields every variant in declaration order, along with an `iter()` associated
+/// function constructing it, and a `const VARIANTS: [Self; N]` array holding the
+/// variants in the same order. Neither requires the enum to be `Copy`.
+///
+/// Passing `primary` additionally generates a `RAWENUM_PRIMARY: &'static str`
+/// associated const holding the name of the first requested type, e.g. `"u8"`,
+/// for macros layered on top of this one that need to know the enum's primary
+/// integer type.
+///
+/// Passing `from_index` additionally generates
+/// `const fn from_index(index: usize) -> Option<Self>`, which returns the variant
+/// declared at the given index, for use with arrays that parallel the variants.
+///
+/// Passing `ordinals` additionally generates `const fn to_ordinal(&self) -> usize`,
+/// which returns the index at which the variant is declared, and
+/// `const ORDINALS: [usize; N]`, holding `0..N` for the `N` variants. Unlike the
+/// discriminants, the ordinals are contiguous, so they can index dispatch tables
+/// such as `[handler; N]`.
+///
+/// Passing `name_of` additionally generates a
+/// `const fn name_of_<type>(value: type) -> Option<&'static str>` function for
+/// each integer type, which returns the name of the variant matching the value,
+/// without constructing the variant, e.g. for log lines. It matches the same
+/// values as `from_<type>`, and returns `None` for the values that would convert
+/// to the catch-all variant.
+///
+/// Passing `variant_name` additionally generates
+/// `const fn variant_name(&self) -> &'static str`, which returns the name of the
+/// variant without requiring `Debug`, e.g. for `no_std` logging without the
+/// formatting machinery.
+///
+/// Passing `variant_count` additionally generates `const VARIANT_COUNT: usize`,
+/// the number of variants of the enum, for sizing arrays indexed by the enum and
+/// checking external tables at compile time. Variants gated behind a `cfg` are
+/// only counted when they are compiled in.
+///
+/// Passing `discriminant_range` additionally generates
+/// `const MIN_DISCRIMINANT: i128` and `const MAX_DISCRIMINANT: i128`, the
+/// smallest and the largest value of a variant, along with
+/// `MIN_DISCRIMINANT_<TYPE>` and `MAX_DISCRIMINANT_<TYPE>` for each integer type,
+/// e.g. `MAX_DISCRIMINANT_U8`, the smallest and the largest value once cast to the
+/// type. They can validate ranges and size dense lookup tables.
+///
+/// Passing `consts` additionally generates a public associated const for each
+/// variant and type, holding the variant's value cast to the type like `to_<type>`
+/// does, e.g. `pub const VARIANT_A_U8: u8`. They can be used as match patterns,
+/// array lengths and FFI constants without calling a function. The variant's name
+/// is converted to SCREAMING_SNAKE_CASE. Passing `consts = "..."` instead sets the
+/// name scheme, in which `{VARIANT}` is replaced by the variant's name and `{TYPE}`
+/// by the type's name in uppercase, e.g. `consts = "RAW_{VARIANT}_{TYPE}"`. The
+/// scheme must contain `{TYPE}` when several types are requested.
+///
+/// Passing `ord_by_value` additionally generates
+/// `cmp_by_discriminant(&self, other: &Self) -> Ordering`, which orders variants
+/// by their discriminant values rather than by their declaration order.
+///
+/// Passing `table` makes `from_u8` index into a static `[Option<Self>; 256]`
+/// lookup table instead of matching, so decoding is a single branchless load,
+/// which is faster for dense enums and hot parsers.
+/// This requires the enum to be `Copy`, which the compiler checks for the enum.
+///
+/// When the enum has a primitive `#[repr(...)]` and the discriminants of the
+/// variants it converts to form a contiguous run of literals, e.g. `0..=199`
+/// for an instruction set, `from_<type>` is generated as a single range check
+/// followed by a transmute, rather than a match with an arm per variant. This
+/// is done for every type that can represent the whole run, unless the
+/// variants have `wire` values, aliases or `cfg` attributes.
+///
+/// Passing `values_from = "<file>"` reads further variants from a file, relative
+/// to the crate's `CARGO_MANIFEST_DIR`, and appends them to the enum, e.g. for
+/// register maps or opcode lists kept in a vendor-provided file. Each line of
+/// the file holds a `NAME = VALUE` pair, and blank lines and lines starting with
+/// `#` are skipped. The file is included into the crate, so editing it triggers
+/// a rebuild.
+///
 /// # Example
 ///
 /// ```rust
@@ -85,148 +553,331 @@ impl Parse for AllowedTypes {
 /// ```
 #[proc_macro_attribute]
 pub fn rawenum(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Hand the item to the type list referenced by `use`, if any, which applies the
+    // macro again with its types along with the remaining arguments
+    let (type_list, attr) = options::split_type_list(attr.into());
+    if let Some(type_list) = type_list {
+        let item = proc_macro2::TokenStream::from(item);
+        return quote! {
+            #type_list! { (#attr) #item }
+        }
+        .into();
+    }
+    let attr = TokenStream::from(attr);
+
+    // Parse the specified integer types and options from the attribute arguments
+    let options = parse_macro_input!(attr as Options);
+
+    // Generate the methods into the impl block the macro was applied to, if any
+    if let Ok(item_impl) = syn::parse::<ItemImpl>(item.clone()) {
+        return codegen::generate_impl(&item_impl, &options)
+            .unwrap_or_else(syn::Error::into_compile_error)
+            .into();
+    }
+
+    let input = parse_macro_input!(item as DeriveInput);
+
+    // Generate the enum along with its methods, or the compile error explaining why we can't
+    codegen::generate(&input, &options)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The derive form of the [`rawenum`](macro@rawenum) macro, which generates the same
+/// methods and items without re-emitting the enum, so it composes with other derives
+/// and with `cfg_attr`.
+///
+/// The arguments of the attribute macro are given in a `#[rawenum(...)]` helper
+/// attribute on the enum instead, with the types listed in `types(...)`, e.g.
+/// `#[rawenum(types(u8, i32), named)]`. Without a helper attribute (or without
+/// types), the type is read from the enum's `#[repr(...)]` attribute. The
+/// variant-level attributes, such as `#[rawenum(wire = ...)]`, are the same, and
+/// `use` type lists aren't supported.
+///
+/// # Example
+///
+/// ```rust
+/// use rawenum::RawEnum;
+///
+/// #[derive(RawEnum, Debug, PartialEq)]
+/// #[rawenum(types(u8, i32), named)]
+/// enum Command {
+///     Read = 1,
+///     #[rawenum(alias = 3)]
+///     Write = 2,
+/// }
+///
+/// assert_eq!(Command::from_u8(3), Some(Command::Write));
+/// assert_eq!(Command::from_i32_named(1), Some((Command::Read, "Read")));
+/// ```
+#[proc_macro_derive(RawEnum, attributes(rawenum))]
+pub fn derive_raw_enum(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
-    let name = &input.ident; // The name of the enum
 
-    // Parse the specified integer types from the attribute arguments
-    let allowed_types = parse_macro_input!(attr as AllowedTypes);
-    let specified_types = allowed_types.types;
+    // Parse the specified integer types and options from the helper attribute, if any
+    let mut helper_attrs = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("rawenum"));
+    let options = match (helper_attrs.next(), helper_attrs.next()) {
+        (_, Some(duplicate)) => Err(syn::Error::new_spanned(
+            duplicate,
+            "only one `#[rawenum(...)]` attribute is allowed on the enum",
+        )),
+        (Some(attr), None) => match &attr.meta {
+            Meta::Path(_) => Ok(Options::default()),
+            _ => attr.parse_args::<Options>(),
+        },
+        (None, None) => Ok(Options::default()),
+    };
+
+    // Generate the methods, or the compile error explaining why we can't
+    options
+        .and_then(|options| {
+            codegen::generate(
+                &input,
+                &Options {
+                    derive: true,
+                    ..options
+                },
+            )
+        })
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The function-like form of the [`rawenum`](macro@rawenum) macro, taking an enum
+/// definition followed by `=>` and the arguments of the attribute macro, e.g.
+/// `rawenum_inline! { #[repr(u8)] pub enum Opcode { Nop = 0x90, Ret = 0xC3 } => u8 }`.
+/// It shares the macro namespace with the attribute macro, hence the different name. It
+/// produces the same enum and conversions, for code generators emitting token streams,
+/// which then don't have to place the attribute among the enum's other attributes.
+/// Without `=>` and arguments, the type is read from the enum's `#[repr(...)]`
+/// attribute.
+///
+/// The enum is passed on to the attribute macro, so the `rawenum` crate must be
+/// available as `::rawenum` where the macro is used.
+///
+/// # Example
+///
+/// ```rust
+/// use rawenum::rawenum_inline;
+///
+/// rawenum_inline! {
+///     #[derive(Debug, PartialEq)]
+///     pub enum Opcode {
+///         Nop = 0x90,
+///         Ret = 0xC3,
+///     } => u8, named
+/// }
+///
+/// assert_eq!(Opcode::from_u8(0xC3), Some(Opcode::Ret));
+/// ```
+#[proc_macro]
+pub fn rawenum_inline(input: TokenStream) -> TokenStream {
+    let InlineEnum { input, arguments } = parse_macro_input!(input as InlineEnum);
+    quote! {
+        #[::rawenum::rawenum(#arguments)]
+        #input
+    }
+    .into()
+}
+
+/// Applies the [`rawenum`](macro@rawenum) macro, with the same arguments, to every enum
+/// in an inline module, including those in nested inline modules, e.g.
+/// `#[rawenum_mod(u8, named)] mod messages { ... }`. Enums that have their own
+/// `#[rawenum(...)]` attribute keep it instead, so they can use other arguments.
+/// Enums whose variants hold fields, other than a catch-all variant, are left
+/// alone, and so are enums marked `#[rawenum(skip)]`.
+///
+/// The enums are passed on to the attribute macro, so the `rawenum` crate must be
+/// available as `::rawenum` where the macro is used.
+///
+/// # Example
+///
+/// ```rust
+/// use rawenum::rawenum_mod;
+///
+/// #[rawenum_mod(u8)]
+/// mod messages {
+///     #[derive(Debug, PartialEq)]
+///     pub enum Request {
+///         Ping = 1,
+///         Query = 2,
+///     }
+///
+///     #[derive(Debug, PartialEq)]
+///     pub enum Response {
+///         Pong = 1,
+///         Answer = 2,
+///     }
+/// }
+///
+/// assert_eq!(messages::Request::from_u8(2), Some(messages::Request::Query));
+/// assert_eq!(messages::Response::from_u8(1), Some(messages::Response::Pong));
+/// ```
+#[proc_macro_attribute]
+pub fn rawenum_mod(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
 
-    // Ensure at least one type was specified
-    if specified_types.is_empty() {
+    // Report invalid arguments once, on the module, rather than on every enum
+    let (_, arguments) = options::split_type_list(attr.clone());
+    if let Err(error) = syn::parse2::<Options>(arguments) {
+        return error.into_compile_error().into();
+    }
+
+    let mut item_mod = parse_macro_input!(item as ItemMod);
+    if item_mod.content.is_none() {
         return syn::Error::new_spanned(
-            input,
-            "at least one integer type must be specified, e.g., #[rawenum(i32)]",
+            &item_mod,
+            "rawenum_mod can only be applied to inline modules, e.g. `mod name { ... }`",
         )
-        .to_compile_error()
+        .into_compile_error()
         .into();
     }
+    annotate_enums(&mut item_mod, &attr);
+    quote! { #item_mod }.into()
+}
 
-    // Ensure the input is an enum, otherwise return a compile error.
-    let Data::Enum(DataEnum { variants, .. }) = &input.data else {
-        return syn::Error::new_spanned(input, "rawenum can only be applied to enums")
-            .to_compile_error()
-            .into();
+// Adds the `rawenum` attribute with the given arguments to the enums in a module and its
+// nested inline modules, except for those that already have one, those marked
+// `#[rawenum(skip)]`, whose marker is removed, and those whose variants hold fields
+fn annotate_enums(item_mod: &mut ItemMod, attr: &proc_macro2::TokenStream) {
+    let Some((_, items)) = &mut item_mod.content else {
+        return;
     };
-
-    // This vector will collect the code for all generated methods.
-    let mut all_generated_methods = Vec::new();
-
-    // Supported integer types for validation
-    const SUPPORTED_TYPES: &[&str] = &["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64"];
-
-    // Generate `impl` block and the `from_*` functions only for specified types
-    for specified_type in specified_types {
-        // Extract the identifier and span from the specified type
-        let (type_ident, type_span) = match &specified_type {
-            Type::Path(type_path) => {
-                if let Some(segment) = type_path.path.segments.last() {
-                    (segment.ident.clone(), segment.span())
-                } else {
-                    return syn::Error::new_spanned(specified_type, "invalid type specified")
-                        .to_compile_error()
-                        .into();
+    for item in items {
+        match item {
+            Item::Enum(item_enum) => {
+                let attrs_before = item_enum.attrs.len();
+                item_enum
+                    .attrs
+                    .retain(|existing| !is_rawenum_marker(existing, "skip"));
+                let skipped = item_enum.attrs.len() != attrs_before;
+                let annotated = item_enum.attrs.iter().any(is_rawenum_attr);
+                // Only a catch-all variant can hold a field, the unmatched value
+                let has_fields = item_enum.variants.iter().any(|variant| {
+                    !variant.fields.is_empty()
+                        && !variant
+                            .attrs
+                            .iter()
+                            .any(|existing| is_rawenum_marker(existing, "catch_all"))
+                });
+                if !skipped && !annotated && !has_fields {
+                    item_enum
+                        .attrs
+                        .insert(0, syn::parse_quote! { #[::rawenum::rawenum(#attr)] });
                 }
             }
-            _ => {
-                return syn::Error::new_spanned(
-                    specified_type,
-                    "expected an integer type identifier (e.g., i32)",
-                )
-                .to_compile_error()
-                .into();
-            }
-        };
-
-        let type_str = type_ident.to_string();
-
-        // Validate that the specified type is one of the supported integer types
-        if !SUPPORTED_TYPES.contains(&type_str.as_str()) {
-            return syn::Error::new_spanned(
-                specified_type,
-                format!(
-                    "unsupported integer type '{}'. Supported types are {}.",
-                    type_str,
-                    SUPPORTED_TYPES.join(", ")
-                ),
-            )
-            .to_compile_error()
-            .into();
+            Item::Mod(nested) => annotate_enums(nested, attr),
+            _ => {}
         }
+    }
+}
 
-        // Create the function name identifier with the correct span
-        let fn_name = format_ident!("from_{}", type_str, span = type_span);
-
-        // Vectors to hold const declarations and match arms *for this specific method*
-        let mut local_generated_consts = Vec::new();
-        let mut local_match_arms = Vec::new();
-
-        // Generate `const` declarations for each variant *within this method*,
-        // casting to the current target integer type.
-        for variant in variants {
-            let variant_name = &variant.ident; // Name of the variant
-            let variant_span = variant_name.span(); // Span of the variant name
-
-            // Create a unique const name for each variant *and* type, with the correct span
-            let const_name = format_ident!(
-                "__RAWENUM_{}_DISCRIMINANT_{}_{}",
-                name.to_string().to_uppercase(),
-                variant_name.to_string().to_uppercase(),
-                type_str.to_uppercase(),
-                span = variant_span
-            );
-
-            // Generate the const declaration:
-            // `const ENUM_VARIANT_DISCRIMINANT_TYPE: TargetType = EnumName::VariantName as TargetType;`
-            // Use #specified_type directly to preserve its span.
-            local_generated_consts.push(quote! {
-                const #const_name: #specified_type = #name::#variant_name as #specified_type;
-            });
-
-            // Generate the match arm using the generated const: `CONST_NAME_TYPE => Some(Self::VariantName),`
-            local_match_arms.push(quote! {
-                #const_name => Some(Self::#variant_name),
-            });
-        }
+// Returns whether an attribute is `rawenum`, given by any path
+fn is_rawenum_attr(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "rawenum")
+}
 
-        // Add the catch-all arm for any value that doesn't match any discriminant
-        // (within the range of the target type after casting the discriminant).
-        local_match_arms.push(quote! {
-            _ => None,
-        });
-
-        // Generate the code for a single `from_*` function
-        let method_code = quote! {
-            #[allow(dead_code)] // Allow this function to be unused without a warning
-            /// Converts a raw #specified_type integer value to an Option<Self>.
-            ///
-            /// Returns `Some(variant)` if the value matches the discriminant
-            /// (when cast to #specified_type) of a variant. Returns `None` otherwise.
-            pub fn #fn_name(value: #specified_type) -> Option<Self> {
-                // Include the locally generated consts here
-                #( #local_generated_consts )*
-
-                // Match the input value directly against the constants of the same type.
-                match value {
-                    // Expand all the collected local match arms
-                    #( #local_match_arms )*
-                }
-            }
-        };
+// Returns whether an attribute is a `rawenum` attribute whose only argument is `marker`,
+// e.g. `#[rawenum(skip)]`
+fn is_rawenum_marker(attr: &Attribute, marker: &str) -> bool {
+    is_rawenum_attr(attr)
+        && attr
+            .parse_args::<Ident>()
+            .is_ok_and(|ident| ident == marker)
+}
 
-        // Add the completed method code to the list.
-        all_generated_methods.push(method_code);
-    }
+/// Generates the `from_<type>` conversions for an enum defined in another crate, which
+/// can't be annotated with the [`rawenum`](macro@rawenum) macro.
+///
+/// The enum's path is followed by `as`, the types, and the variants along with their
+/// values, e.g. `rawenum_remote! { for other_crate::Status as u32, u8 { Ok = 0, Err = 1 } }`.
+/// The methods are provided by a generated `<Enum>RawExt` extension trait, e.g.
+/// `StatusRawExt`, which must be in scope to call them, and only match the listed
+/// variants. Const assertions check that the listed values are the discriminants of
+/// the variants, so the list can't silently go stale when the other crate changes
+/// them.
+///
+/// # Example
+///
+/// ```rust
+/// use rawenum::rawenum_remote;
+///
+/// mod other_crate {
+///     pub enum Status {
+///         Ok = 0,
+///         Err = 1,
+///     }
+/// }
+///
+/// rawenum_remote! {
+///     for other_crate::Status as u32 {
+///         Ok = 0,
+///         Err = 1,
+///     }
+/// }
+///
+/// assert!(matches!(
+///     other_crate::Status::from_u32(1),
+///     Some(other_crate::Status::Err)
+/// ));
+/// ```
+#[proc_macro]
+pub fn rawenum_remote(input: TokenStream) -> TokenStream {
+    let remote = parse_macro_input!(input as RemoteEnum);
 
-    // Combine the original enum definition and the generated methods within the impl block.
-    let expanded = quote! {
-        #input // Include the original enum definition
+    // Generate the extension trait, or the compile error explaining why we can't
+    codegen::generate_remote(&remote)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-        impl #name {
-            #( #all_generated_methods )* // Expand all the generated from_* methods
+/// Defines a reusable list of types (and other arguments) for the `rawenum` macro, to
+/// avoid repeating the same list on many enums.
+///
+/// `rawenum_types!(MyTypes = i32, u8);` defines the list, and `#[rawenum(use MyTypes)]`
+/// applies `#[rawenum(i32, u8)]`. Any other arguments are appended to the list, e.g.
+/// `#[rawenum(use MyTypes, named)]` applies `#[rawenum(i32, u8, named)]`.
+///
+/// The list is defined as a `macro_rules!` macro with the given name, which the
+/// `rawenum` macro invokes with the item. It therefore follows the scoping rules of
+/// `macro_rules!` macros: it must be defined before it is used, either earlier in
+/// the same module or in a parent module, or be imported by path after
+/// `pub(crate) use MyTypes;`. The `rawenum` crate must be
+/// available as `::rawenum` where the list is used.
+///
+/// # Example
+///
+/// ```rust
+/// use rawenum::{rawenum, rawenum_types};
+///
+/// rawenum_types!(ProtocolTypes = i32, u8);
+///
+/// #[rawenum(use ProtocolTypes)]
+/// #[derive(Debug, PartialEq)]
+/// enum Command {
+///     Read = 1,
+///     Write = 2,
+/// }
+///
+/// assert_eq!(Command::from_i32(2), Some(Command::Write));
+/// assert_eq!(Command::from_u8(1), Some(Command::Read));
+/// ```
+#[proc_macro]
+pub fn rawenum_types(input: TokenStream) -> TokenStream {
+    let TypeList { name, arguments } = parse_macro_input!(input as TypeList);
+    quote! {
+        macro_rules! #name {
+            (($($arguments:tt)*) $($item:tt)*) => {
+                #[::rawenum::rawenum(#arguments, $($arguments)*)]
+                $($item)*
+            };
         }
-    };
-
-    // Convert the generated code back to a TokenStream and return it
-    expanded.into()
+    }
+    .into()
 }
@@ -1,4 +1,4 @@
-use rawenum::rawenum;
+use rawenum::{rawenum, rawenum_inline, rawenum_mod, rawenum_remote};
 
 // Note on testing for absence of methods:
 // To rigorously test that methods for *other* types are *not* generated,
@@ -35,6 +35,61 @@ fn test_explicit_enum_i32_u8() {
     assert_eq!(ExplicitEnum::from_u8(99), None); // No match
 }
 
+#[test]
+fn test_explicit_enum_to_methods() {
+    assert_eq!(ExplicitEnum::Ten.to_i32(), 10);
+    assert_eq!(ExplicitEnum::NegativeFive.to_i32(), -5);
+    assert_eq!(ExplicitEnum::Ten.to_u8(), 10);
+    assert_eq!(ExplicitEnum::NegativeFive.to_u8(), 251); // -5 as u8 is 251
+
+    // The reverse conversions round-trip
+    for variant in [
+        ExplicitEnum::Zero,
+        ExplicitEnum::One,
+        ExplicitEnum::Ten,
+        ExplicitEnum::NegativeFive,
+    ] {
+        assert_eq!(ExplicitEnum::from_u8(variant.to_u8()), Some(variant));
+    }
+
+    // Usable in const contexts
+    const ONE: i32 = ExplicitEnum::One.to_i32();
+    assert_eq!(ONE, 1);
+}
+
+// Takes the `Option` an iterator or a parser yields, rather than a bare value
+#[rawenum(i32, u8, opt_helpers)]
+#[derive(Debug, PartialEq)]
+enum OptionalEnum {
+    Zero = 0,
+    One = 1,
+    Ten = 10,
+    NegativeFive = -5,
+}
+
+#[test]
+fn test_optional_enum_opt_helpers() {
+    assert_eq!(
+        OptionalEnum::from_opt_i32(Some(10)),
+        Some(OptionalEnum::Ten)
+    );
+    assert_eq!(
+        OptionalEnum::from_opt_u8(Some(251)),
+        Some(OptionalEnum::NegativeFive)
+    );
+    assert_eq!(OptionalEnum::from_opt_i32(Some(99)), None); // No match
+    assert_eq!(OptionalEnum::from_opt_i32(None), None);
+
+    // Chains straight off an iterator
+    let mut values = [1, 2].into_iter();
+    assert_eq!(
+        OptionalEnum::from_opt_i32(values.next()),
+        Some(OptionalEnum::One)
+    );
+    assert_eq!(OptionalEnum::from_opt_i32(values.next()), None);
+    assert_eq!(OptionalEnum::from_opt_i32(values.next()), None);
+}
+
 // --- Test Case 2: Enum with only implicit discriminants, specifying i16 and u64 ---
 #[rawenum(i16, u64)]
 #[derive(Debug, PartialEq)]
@@ -92,6 +147,161 @@ fn test_mixed_enum_i8_i64() {
     assert_eq!(MixedEnum::from_i64(300), None); // After range
 }
 
+// Iterated in declaration order, which isn't the order of the values as i8
+#[rawenum(i8, i64, iter)]
+#[derive(Debug, PartialEq)]
+enum IterEnum {
+    Start = 100,
+    Next,
+    Jump = 200,
+    Another,
+    End,
+}
+
+#[test]
+fn test_iter_enum_iter() {
+    // Variants are yielded in declaration order
+    let all: Vec<IterEnum> = IterEnum::iter().collect();
+    assert_eq!(
+        all,
+        vec![
+            IterEnum::Start,
+            IterEnum::Next,
+            IterEnum::Jump,
+            IterEnum::Another,
+            IterEnum::End,
+        ]
+    );
+
+    // The iterator is exact-sized and can be named in signatures
+    let mut iter: IterEnumIter = IterEnum::iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(IterEnum::Start));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.nth(3), Some(IterEnum::End));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut count = 0;
+    for variant in IterEnum::iter() {
+        assert!(IterEnum::from_i64(variant as i64).is_some());
+        count += 1;
+    }
+    assert_eq!(count, 5);
+
+    // The same variants are in the `VARIANTS` array, which doesn't need `Copy`
+    assert_eq!(IterEnum::VARIANTS.len(), 5);
+    assert_eq!(IterEnum::VARIANTS[2], IterEnum::Jump);
+    assert!(IterEnum::VARIANTS.into_iter().eq(IterEnum::iter()));
+}
+
+// Spans 100..=202 as i64, and -56..=101 as i8 since the larger values wrap
+#[rawenum(i8, i64, try_from)]
+#[derive(Debug, PartialEq)]
+enum RangedEnum {
+    Start = 100,
+    Next,
+    Jump = 200,
+    Another,
+    End,
+}
+
+#[test]
+fn test_ranged_enum_try_from() {
+    // The discriminants span 100..=202 as i64
+    assert_eq!(RangedEnum::try_from(100i64), Ok(RangedEnum::Start));
+    assert_eq!(RangedEnum::try_from(202i64), Ok(RangedEnum::End));
+    assert_eq!(
+        RangedEnum::try_from(150i64),
+        Err(RangedEnumParseError::NoVariant(150))
+    );
+    assert_eq!(
+        RangedEnum::try_from(99i64),
+        Err(RangedEnumParseError::OutOfRange(99))
+    );
+    assert_eq!(
+        RangedEnum::try_from(203i64),
+        Err(RangedEnumParseError::OutOfRange(203))
+    );
+
+    // The discriminants span -56..=101 as i8, since the larger ones wrap
+    assert_eq!(RangedEnum::try_from(-56i8), Ok(RangedEnum::Jump));
+    assert_eq!(
+        RangedEnum::try_from(0i8),
+        Err(RangedEnumParseError::NoVariant(0))
+    );
+    assert_eq!(
+        RangedEnum::try_from(102i8),
+        Err(RangedEnumParseError::OutOfRange(102))
+    );
+    assert_eq!(
+        RangedEnum::try_from(-57i8),
+        Err(RangedEnumParseError::OutOfRange(-57))
+    );
+
+    assert_eq!(
+        RangedEnumParseError::NoVariant(150i64).to_string(),
+        "value 150 matches no variant"
+    );
+}
+
+// The larger values wrap as i8, so they sort before the smaller ones
+#[rawenum(i8, i64, sorted_entries)]
+#[derive(Debug, PartialEq)]
+enum SortedEnum {
+    Start = 100,
+    Next,
+    Jump = 200,
+    Another,
+    End,
+}
+
+#[test]
+fn test_sorted_enum_sorted_entries() {
+    // Sorted by value rather than by declaration order, since the larger ones wrap as i8
+    assert_eq!(
+        SortedEnum::sorted_entries_i8(),
+        &[
+            (-56, SortedEnum::Jump),
+            (-55, SortedEnum::Another),
+            (-54, SortedEnum::End),
+            (100, SortedEnum::Start),
+            (101, SortedEnum::Next),
+        ]
+    );
+
+    let entries = SortedEnum::sorted_entries_i64();
+    assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(entries.first(), Some(&(100, SortedEnum::Start)));
+    assert_eq!(entries.last(), Some(&(202, SortedEnum::End)));
+}
+
+// The values have gaps, while the indices are contiguous
+#[rawenum(i8, i64, from_index)]
+#[derive(Debug, PartialEq)]
+enum IndexedEnum {
+    Start = 100,
+    Next,
+    Jump = 200,
+    Another,
+    End,
+}
+
+#[test]
+fn test_indexed_enum_from_index() {
+    // Indices follow declaration order, regardless of the discriminants
+    assert_eq!(IndexedEnum::from_index(0), Some(IndexedEnum::Start));
+    assert_eq!(IndexedEnum::from_index(2), Some(IndexedEnum::Jump));
+    assert_eq!(IndexedEnum::from_index(4), Some(IndexedEnum::End));
+    assert_eq!(IndexedEnum::from_index(5), None);
+    assert_eq!(IndexedEnum::from_index(usize::MAX), None);
+
+    // Usable in const contexts
+    const FIRST: Option<IndexedEnum> = IndexedEnum::from_index(0);
+    assert_eq!(FIRST, Some(IndexedEnum::Start));
+}
+
 // --- Test Case 4: Enum with zero discriminant, specifying i32 ---
 #[rawenum(i32)]
 #[derive(Debug, PartialEq)]
@@ -107,6 +317,61 @@ fn test_zero_enum_i32() {
     assert_eq!(ZeroEnum::from_i32(2), None);
 }
 
+// Panics with the bad value, for values already validated elsewhere
+#[rawenum(i32, expect)]
+#[derive(Debug, PartialEq)]
+enum ExpectedEnum {
+    First = 0,
+    Second,
+}
+
+#[test]
+fn test_expected_enum_expect_i32() {
+    assert_eq!(ExpectedEnum::expect_i32(1), ExpectedEnum::Second);
+
+    // The panic message contains the bad value
+    let panic = std::panic::catch_unwind(|| ExpectedEnum::expect_i32(42)).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert_eq!(message, "invalid raw i32 value for `ExpectedEnum`: 42");
+}
+
+#[test]
+#[should_panic(expected = "invalid raw i32 value for `ExpectedEnum`: -7")]
+fn test_expected_enum_expect_i32_panics() {
+    let _ = ExpectedEnum::expect_i32(-7);
+}
+
+// Converted into `ControlFlow`, so `?` breaks out of `try_for_each` with the bad value
+#[rawenum(i32, control_flow)]
+#[derive(Debug, PartialEq)]
+enum FlowEnum {
+    First = 0,
+    Second,
+}
+
+#[test]
+fn test_flow_enum_from_i32_flow() {
+    use std::ops::ControlFlow;
+
+    assert_eq!(
+        FlowEnum::from_i32_flow(1),
+        ControlFlow::Continue(FlowEnum::Second)
+    );
+    assert_eq!(FlowEnum::from_i32_flow(5), ControlFlow::Break(5));
+
+    // Drives a decoding loop that stops at the first unknown value
+    let mut decoded = Vec::new();
+    let stopped_at = [0, 1, 0, 9, 1].into_iter().try_for_each(|value| {
+        decoded.push(FlowEnum::from_i32_flow(value)?);
+        ControlFlow::Continue(())
+    });
+    assert_eq!(stopped_at, ControlFlow::Break(9));
+    assert_eq!(
+        decoded,
+        vec![FlowEnum::First, FlowEnum::Second, FlowEnum::First]
+    );
+}
+
 // --- Test Case 5: Enum with negative discriminants, specifying i8 and u8 ---
 #[rawenum(i8, u8)]
 #[derive(Debug, PartialEq)]
@@ -133,3 +398,3125 @@ fn test_negative_enum_i8_u8() {
     assert_eq!(NegativeEnum::from_u8(1), Some(NegativeEnum::One));
     assert_eq!(NegativeEnum::from_u8(99), None);
 }
+
+// Declared out of value order, so `cmp_by_discriminant` differs from declaration order
+#[rawenum(i8, u8, ord_by_value)]
+#[derive(Debug, PartialEq)]
+enum OrderedEnum {
+    NegOne = -1,
+    NegTwo = -2,
+    Zero = 0,
+    One = 1,
+}
+
+#[test]
+fn test_ordered_enum_cmp_by_discriminant() {
+    use std::cmp::Ordering;
+
+    // Declaration order is NegOne, NegTwo, Zero, One, but value order is NegTwo, NegOne, Zero, One
+    assert_eq!(
+        OrderedEnum::NegOne.cmp_by_discriminant(&OrderedEnum::NegTwo),
+        Ordering::Greater
+    );
+    assert_eq!(
+        OrderedEnum::NegTwo.cmp_by_discriminant(&OrderedEnum::Zero),
+        Ordering::Less
+    );
+    assert_eq!(
+        OrderedEnum::One.cmp_by_discriminant(&OrderedEnum::One),
+        Ordering::Equal
+    );
+
+    let mut sorted = vec![
+        OrderedEnum::One,
+        OrderedEnum::NegOne,
+        OrderedEnum::Zero,
+        OrderedEnum::NegTwo,
+    ];
+    sorted.sort_by(OrderedEnum::cmp_by_discriminant);
+    assert_eq!(
+        sorted,
+        vec![
+            OrderedEnum::NegTwo,
+            OrderedEnum::NegOne,
+            OrderedEnum::Zero,
+            OrderedEnum::One,
+        ]
+    );
+}
+
+// --- Test Case 6: Methods gated behind a `cfg(...)` group ---
+#[rawenum(u8, cfg(all()), i32)]
+#[derive(Debug, PartialEq)]
+enum CfgEnum {
+    A = 1,
+    B = 2,
+}
+
+#[test]
+fn test_cfg_enum_u8_i32() {
+    // from_u8 is not gated
+    assert_eq!(CfgEnum::from_u8(1), Some(CfgEnum::A));
+
+    // from_i32 is gated behind `cfg(all())`, which is always true
+    assert_eq!(CfgEnum::from_i32(2), Some(CfgEnum::B));
+    assert_eq!(CfgEnum::from_i32(3), None);
+}
+
+// --- Test Case 7: Name-based reverse lookup ---
+#[rawenum(u8, name_lookup)]
+#[derive(Debug, PartialEq)]
+enum NamedEnum {
+    Alpha = 1,
+    Beta,
+    Gamma = 10,
+}
+
+#[test]
+fn test_named_enum_variant_for_name() {
+    assert_eq!(NamedEnum::variant_for_name("Alpha"), Some(NamedEnum::Alpha));
+    assert_eq!(NamedEnum::variant_for_name("Beta"), Some(NamedEnum::Beta));
+    assert_eq!(NamedEnum::variant_for_name("Gamma"), Some(NamedEnum::Gamma));
+    assert_eq!(NamedEnum::variant_for_name("alpha"), None); // Names are case sensitive
+    assert_eq!(NamedEnum::variant_for_name("Delta"), None); // Unknown name
+    assert_eq!(NamedEnum::variant_for_name(""), None);
+
+    // The integer conversions are still generated
+    assert_eq!(NamedEnum::from_u8(2), Some(NamedEnum::Beta));
+}
+
+#[rawenum(u8, name_lookup, case_insensitive)]
+#[derive(Debug, PartialEq)]
+enum ConfigToken {
+    VariantA,
+    VariantB,
+    Verbose,
+    r#Loop,
+}
+
+#[test]
+fn test_config_token_variant_for_name_case_insensitive() {
+    assert_eq!(
+        ConfigToken::variant_for_name("VariantA"),
+        Some(ConfigToken::VariantA)
+    );
+    assert_eq!(
+        ConfigToken::variant_for_name("varianta"),
+        Some(ConfigToken::VariantA)
+    );
+    assert_eq!(
+        ConfigToken::variant_for_name("VARIANTB"),
+        Some(ConfigToken::VariantB)
+    );
+    assert_eq!(
+        ConfigToken::variant_for_name("vErBoSe"),
+        Some(ConfigToken::Verbose)
+    );
+    assert_eq!(
+        ConfigToken::variant_for_name("LOOP"),
+        Some(ConfigToken::Loop)
+    );
+    assert_eq!(ConfigToken::variant_for_name("VariantC"), None); // Unknown name
+    assert_eq!(ConfigToken::variant_for_name("Variant"), None); // Prefix only
+    assert_eq!(ConfigToken::variant_for_name(""), None);
+}
+
+// --- Test Case 8: Enum with byte literal discriminants, specifying u8 and i8 ---
+#[rawenum(u8, i8)]
+#[derive(Debug, PartialEq)]
+enum ByteLiteralEnum {
+    Add = b'+' as isize,
+    Sub = b'-' as isize,
+    Mul = b'*' as isize,
+    Eq = b'=' as isize,
+    // Implicit discriminant (b'=' + 1, which is b'>')
+    Gt,
+    High = b'\xF0' as isize, // 0xF0 as i8 is -16
+}
+
+#[test]
+fn test_byte_literal_enum_u8_i8() {
+    // Test from_u8 (should be generated)
+    assert_eq!(ByteLiteralEnum::from_u8(b'+'), Some(ByteLiteralEnum::Add));
+    assert_eq!(ByteLiteralEnum::from_u8(b'-'), Some(ByteLiteralEnum::Sub));
+    assert_eq!(ByteLiteralEnum::from_u8(b'*'), Some(ByteLiteralEnum::Mul));
+    assert_eq!(ByteLiteralEnum::from_u8(b'>'), Some(ByteLiteralEnum::Gt));
+    assert_eq!(
+        ByteLiteralEnum::from_u8(b'\xF0'),
+        Some(ByteLiteralEnum::High)
+    );
+    assert_eq!(ByteLiteralEnum::from_u8(b'/'), None);
+
+    // Test from_i8 (should be generated, checking wrapping)
+    assert_eq!(
+        ByteLiteralEnum::from_i8(b'+' as i8),
+        Some(ByteLiteralEnum::Add)
+    );
+    assert_eq!(
+        ByteLiteralEnum::from_i8(b'-' as i8),
+        Some(ByteLiteralEnum::Sub)
+    );
+    assert_eq!(
+        ByteLiteralEnum::from_i8(b'>' as i8),
+        Some(ByteLiteralEnum::Gt)
+    );
+    assert_eq!(ByteLiteralEnum::from_i8(-16), Some(ByteLiteralEnum::High)); // 0xF0 wraps to -16
+    assert_eq!(ByteLiteralEnum::from_i8(b'/' as i8), None);
+}
+
+// --- Test Case 9: Lookup table strategy for from_u8 ---
+#[rawenum(u8, i32, table)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TableEnum {
+    Zero,          // 0
+    One,           // 1
+    Five = 5,      // 5
+    Wrapped = 256, // 256 as u8 is 0, collides with Zero
+    Max = 255,     // 255 as u8 is 255
+}
+
+#[test]
+fn test_table_enum_u8_i32() {
+    // Test from_u8 (generated using the lookup table)
+    assert_eq!(TableEnum::from_u8(0), Some(TableEnum::Zero)); // First declared variant wins
+    assert_eq!(TableEnum::from_u8(1), Some(TableEnum::One));
+    assert_eq!(TableEnum::from_u8(5), Some(TableEnum::Five));
+    assert_eq!(TableEnum::from_u8(255), Some(TableEnum::Max));
+    assert_eq!(TableEnum::from_u8(2), None); // Gap in the table
+    assert_eq!(TableEnum::from_u8(254), None);
+
+    // Test from_i32 (unaffected by the table option)
+    assert_eq!(TableEnum::from_i32(256), Some(TableEnum::Wrapped));
+    assert_eq!(TableEnum::from_i32(0), Some(TableEnum::Zero));
+    assert_eq!(TableEnum::from_i32(2), None);
+}
+
+#[rawenum(u8, table)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SmallTableEnum {
+    A = 1,
+    B = 3,
+}
+
+#[test]
+fn test_small_table_enum_u8() {
+    // The table spans every u8, with empty entries past the largest discriminant
+    assert_eq!(SmallTableEnum::from_u8(0), None);
+    assert_eq!(SmallTableEnum::from_u8(1), Some(SmallTableEnum::A));
+    assert_eq!(SmallTableEnum::from_u8(2), None);
+    assert_eq!(SmallTableEnum::from_u8(3), Some(SmallTableEnum::B));
+    assert_eq!(SmallTableEnum::from_u8(4), None);
+    assert_eq!(SmallTableEnum::from_u8(200), None);
+    assert_eq!(SmallTableEnum::from_u8(u8::MAX), None);
+}
+
+// --- Test Case 10: Enum with a deprecated variant ---
+// The generated consts reference the deprecated variant, which triggers the
+// `deprecated` lint (see tests/ui/deprecated_variant.rs), so allow it here.
+#[allow(deprecated)]
+mod deprecated_enum {
+    use rawenum::rawenum;
+
+    #[rawenum(u8)]
+    #[derive(Debug, PartialEq)]
+    pub enum DeprecatedEnum {
+        Current = 1,
+        #[deprecated(note = "use `Current` instead")]
+        Legacy = 2,
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_enum_u8() {
+    use deprecated_enum::DeprecatedEnum;
+
+    assert_eq!(DeprecatedEnum::from_u8(1), Some(DeprecatedEnum::Current));
+    assert_eq!(DeprecatedEnum::from_u8(2), Some(DeprecatedEnum::Legacy));
+    assert_eq!(DeprecatedEnum::from_u8(3), None);
+}
+
+// --- Test Case 11: Two-valued enum converted from bool ---
+#[rawenum(bool, u8)]
+#[derive(Debug, PartialEq)]
+enum BoolEnum {
+    Off, // 0
+    On,  // 1
+}
+
+#[rawenum(bool)]
+#[derive(Debug, PartialEq)]
+enum OneSidedBoolEnum {
+    Set = 1,
+    Other = 2, // Unreachable from from_bool
+}
+
+#[test]
+fn test_bool_enum() {
+    assert_eq!(BoolEnum::from_bool(false), Some(BoolEnum::Off));
+    assert_eq!(BoolEnum::from_bool(true), Some(BoolEnum::On));
+    assert_eq!(BoolEnum::from_u8(1), Some(BoolEnum::On));
+
+    assert_eq!(OneSidedBoolEnum::from_bool(false), None);
+    assert_eq!(
+        OneSidedBoolEnum::from_bool(true),
+        Some(OneSidedBoolEnum::Set)
+    );
+}
+
+// --- Test Case 12: Opting out of #[must_use] ---
+#[rawenum(u8, no_must_use)]
+#[derive(Debug, PartialEq)]
+enum NoMustUseEnum {
+    A = 1,
+}
+
+#[test]
+#[deny(unused_must_use)]
+fn test_no_must_use_enum_u8() {
+    // Discarding the result compiles without a warning
+    NoMustUseEnum::from_u8(1);
+    assert_eq!(NoMustUseEnum::from_u8(1), Some(NoMustUseEnum::A));
+}
+
+// --- Test Case 13: Exhaustive u8 enum covering all 256 values ---
+// Defines an enum with a variant for each of the 256 byte values, `V0` to `V255`
+macro_rules! byte_enum {
+    ($(#[$attr:meta])* enum $name:ident) => {
+        $(#[$attr])*
+        enum $name {
+            V0,
+            V1,
+            V2,
+            V3,
+            V4,
+            V5,
+            V6,
+            V7,
+            V8,
+            V9,
+            V10,
+            V11,
+            V12,
+            V13,
+            V14,
+            V15,
+            V16,
+            V17,
+            V18,
+            V19,
+            V20,
+            V21,
+            V22,
+            V23,
+            V24,
+            V25,
+            V26,
+            V27,
+            V28,
+            V29,
+            V30,
+            V31,
+            V32,
+            V33,
+            V34,
+            V35,
+            V36,
+            V37,
+            V38,
+            V39,
+            V40,
+            V41,
+            V42,
+            V43,
+            V44,
+            V45,
+            V46,
+            V47,
+            V48,
+            V49,
+            V50,
+            V51,
+            V52,
+            V53,
+            V54,
+            V55,
+            V56,
+            V57,
+            V58,
+            V59,
+            V60,
+            V61,
+            V62,
+            V63,
+            V64,
+            V65,
+            V66,
+            V67,
+            V68,
+            V69,
+            V70,
+            V71,
+            V72,
+            V73,
+            V74,
+            V75,
+            V76,
+            V77,
+            V78,
+            V79,
+            V80,
+            V81,
+            V82,
+            V83,
+            V84,
+            V85,
+            V86,
+            V87,
+            V88,
+            V89,
+            V90,
+            V91,
+            V92,
+            V93,
+            V94,
+            V95,
+            V96,
+            V97,
+            V98,
+            V99,
+            V100,
+            V101,
+            V102,
+            V103,
+            V104,
+            V105,
+            V106,
+            V107,
+            V108,
+            V109,
+            V110,
+            V111,
+            V112,
+            V113,
+            V114,
+            V115,
+            V116,
+            V117,
+            V118,
+            V119,
+            V120,
+            V121,
+            V122,
+            V123,
+            V124,
+            V125,
+            V126,
+            V127,
+            V128,
+            V129,
+            V130,
+            V131,
+            V132,
+            V133,
+            V134,
+            V135,
+            V136,
+            V137,
+            V138,
+            V139,
+            V140,
+            V141,
+            V142,
+            V143,
+            V144,
+            V145,
+            V146,
+            V147,
+            V148,
+            V149,
+            V150,
+            V151,
+            V152,
+            V153,
+            V154,
+            V155,
+            V156,
+            V157,
+            V158,
+            V159,
+            V160,
+            V161,
+            V162,
+            V163,
+            V164,
+            V165,
+            V166,
+            V167,
+            V168,
+            V169,
+            V170,
+            V171,
+            V172,
+            V173,
+            V174,
+            V175,
+            V176,
+            V177,
+            V178,
+            V179,
+            V180,
+            V181,
+            V182,
+            V183,
+            V184,
+            V185,
+            V186,
+            V187,
+            V188,
+            V189,
+            V190,
+            V191,
+            V192,
+            V193,
+            V194,
+            V195,
+            V196,
+            V197,
+            V198,
+            V199,
+            V200,
+            V201,
+            V202,
+            V203,
+            V204,
+            V205,
+            V206,
+            V207,
+            V208,
+            V209,
+            V210,
+            V211,
+            V212,
+            V213,
+            V214,
+            V215,
+            V216,
+            V217,
+            V218,
+            V219,
+            V220,
+            V221,
+            V222,
+            V223,
+            V224,
+            V225,
+            V226,
+            V227,
+            V228,
+            V229,
+            V230,
+            V231,
+            V232,
+            V233,
+            V234,
+            V235,
+            V236,
+            V237,
+            V238,
+            V239,
+            V240,
+            V241,
+            V242,
+            V243,
+            V244,
+            V245,
+            V246,
+            V247,
+            V248,
+            V249,
+            V250,
+            V251,
+            V252,
+            V253,
+            V254,
+            V255,
+        }
+    };
+}
+
+byte_enum! {
+    #[rawenum(u8, i32, exhaustive)]
+    #[derive(Debug, PartialEq)]
+    #[repr(u8)]
+    enum FullByteEnum
+}
+
+#[test]
+fn test_full_byte_enum_exhaustive_u8() {
+    // from_u8 is infallible and returns Self directly
+    assert_eq!(FullByteEnum::from_u8(0), FullByteEnum::V0);
+    assert_eq!(FullByteEnum::from_u8(1), FullByteEnum::V1);
+    assert_eq!(FullByteEnum::from_u8(128), FullByteEnum::V128);
+    assert_eq!(FullByteEnum::from_u8(255), FullByteEnum::V255);
+
+    // from_i32 is not an 8-bit conversion and still returns an Option
+    assert_eq!(FullByteEnum::from_i32(255), Some(FullByteEnum::V255));
+    assert_eq!(FullByteEnum::from_i32(256), None);
+}
+
+// --- Test Case 14: Applying the macro through cfg_attr ---
+// `all()` is always true and `any()` is always false, standing in for a
+// `feature = "..."` predicate that is enabled or disabled.
+#[cfg_attr(all(), rawenum(u8))]
+#[derive(Debug, PartialEq)]
+enum CfgAttrEnabledEnum {
+    A = 1,
+}
+
+#[cfg_attr(any(), rawenum(u8))]
+#[derive(Debug, PartialEq)]
+enum CfgAttrDisabledEnum {
+    A = 1,
+}
+
+// This would conflict with the generated method if the macro had been applied
+impl CfgAttrDisabledEnum {
+    fn from_u8(_value: u8) -> Option<Self> {
+        None
+    }
+}
+
+#[test]
+fn test_cfg_attr_enum_u8() {
+    assert_eq!(CfgAttrEnabledEnum::from_u8(1), Some(CfgAttrEnabledEnum::A));
+
+    // With the predicate off, only the hand-written method exists
+    assert_eq!(CfgAttrDisabledEnum::from_u8(1), None);
+    assert_eq!(CfgAttrDisabledEnum::A, CfgAttrDisabledEnum::A);
+}
+
+// --- Test Case 15: Conversions that also return the variant name ---
+#[rawenum(i32, u8, named)]
+#[derive(Debug, PartialEq)]
+enum NamedConversionEnum {
+    Request = 1,
+    Response,
+    Error = -1, // -1 as u8 is 255
+}
+
+#[test]
+fn test_named_conversion_enum_i32_u8() {
+    assert_eq!(
+        NamedConversionEnum::from_i32_named(1),
+        Some((NamedConversionEnum::Request, "Request"))
+    );
+    assert_eq!(
+        NamedConversionEnum::from_i32_named(2),
+        Some((NamedConversionEnum::Response, "Response"))
+    );
+    assert_eq!(
+        NamedConversionEnum::from_i32_named(-1),
+        Some((NamedConversionEnum::Error, "Error"))
+    );
+    assert_eq!(NamedConversionEnum::from_i32_named(3), None);
+
+    assert_eq!(
+        NamedConversionEnum::from_u8_named(255),
+        Some((NamedConversionEnum::Error, "Error"))
+    );
+    assert_eq!(NamedConversionEnum::from_u8_named(0), None);
+
+    // The plain conversions are still generated
+    assert_eq!(
+        NamedConversionEnum::from_i32(2),
+        Some(NamedConversionEnum::Response)
+    );
+}
+
+// --- Test Case 16: Enum with block expression discriminants, specifying i32 and u8 ---
+#[rawenum(i32, u8)]
+#[derive(Debug, PartialEq)]
+#[repr(i32)]
+enum BlockExprEnum {
+    Computed = {
+        const BASE: i32 = 3;
+        BASE * 2
+    }, // 6
+    Next, // 7
+    Negative = {
+        let offset = 10;
+        -offset
+    }, // -10, which is 246 as u8
+}
+
+#[test]
+fn test_block_expr_enum_i32_u8() {
+    assert_eq!(BlockExprEnum::from_i32(6), Some(BlockExprEnum::Computed));
+    assert_eq!(BlockExprEnum::from_i32(7), Some(BlockExprEnum::Next));
+    assert_eq!(BlockExprEnum::from_i32(-10), Some(BlockExprEnum::Negative));
+    assert_eq!(BlockExprEnum::from_i32(3), None);
+
+    assert_eq!(BlockExprEnum::from_u8(6), Some(BlockExprEnum::Computed));
+    assert_eq!(BlockExprEnum::from_u8(246), Some(BlockExprEnum::Negative));
+    assert_eq!(BlockExprEnum::from_u8(10), None);
+}
+
+// --- Test Case 17: Conversions restricted to a subset of variants ---
+#[rawenum(u8, only(Ping, Pong))]
+#[derive(Debug, PartialEq)]
+enum OnlyEnum {
+    Ping = 1,
+    Pong = 2,
+    Internal = 3, // Not externally representable
+}
+
+#[test]
+fn test_only_enum_u8() {
+    assert_eq!(OnlyEnum::from_u8(1), Some(OnlyEnum::Ping));
+    assert_eq!(OnlyEnum::from_u8(2), Some(OnlyEnum::Pong));
+    assert_eq!(OnlyEnum::from_u8(3), None); // Excluded variant
+    assert_eq!(OnlyEnum::Internal, OnlyEnum::Internal);
+}
+
+// --- Test Case 18: Name lookup with names sharing lengths and first bytes ---
+#[rawenum(u8, name_lookup)]
+#[derive(Debug, PartialEq)]
+enum OpcodeNameEnum {
+    Add,
+    And,
+    Adc,
+    Sub,
+    Sbb,
+    Shl,
+    Shr,
+    Jmp,
+    Call,
+    Cmp,
+    Push,
+    Pop,
+    r#Loop,
+}
+
+#[test]
+fn test_opcode_name_enum_variant_for_name() {
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("Add"),
+        Some(OpcodeNameEnum::Add)
+    );
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("And"),
+        Some(OpcodeNameEnum::And)
+    );
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("Adc"),
+        Some(OpcodeNameEnum::Adc)
+    );
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("Shr"),
+        Some(OpcodeNameEnum::Shr)
+    );
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("Call"),
+        Some(OpcodeNameEnum::Call)
+    );
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("Pop"),
+        Some(OpcodeNameEnum::Pop)
+    );
+    // Raw identifiers are looked up without their `r#` prefix
+    assert_eq!(
+        OpcodeNameEnum::variant_for_name("Loop"),
+        Some(OpcodeNameEnum::Loop)
+    );
+    assert_eq!(OpcodeNameEnum::variant_for_name("r#Loop"), None);
+
+    // Same length and first byte as existing names, but no match
+    assert_eq!(OpcodeNameEnum::variant_for_name("Axe"), None);
+    // Same length but no name starts with this byte
+    assert_eq!(OpcodeNameEnum::variant_for_name("Xor"), None);
+    // No name has this length
+    assert_eq!(OpcodeNameEnum::variant_for_name("Return"), None);
+    assert_eq!(OpcodeNameEnum::variant_for_name(""), None);
+}
+
+// --- Test Case 19: Newtype struct wrapping an enum ---
+#[rawenum(u8, i32, named)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TagKind {
+    Start = 1,
+    Stop = 2,
+}
+
+#[rawenum(u8, named)]
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+struct Tag(TagKind);
+
+#[test]
+fn test_newtype_tag_u8() {
+    assert_eq!(Tag::from_u8(1), Some(Tag(TagKind::Start)));
+    assert_eq!(Tag::from_u8(2), Some(Tag(TagKind::Stop)));
+    assert_eq!(Tag::from_u8(3), None);
+
+    assert_eq!(Tag::from_u8_named(2), Some((Tag(TagKind::Stop), "Stop")));
+    assert_eq!(Tag::from_u8_named(0), None);
+
+    assert_eq!(Tag(TagKind::Stop).to_u8(), 2);
+}
+
+// --- Test Case 20: Saturating conversions to the requested types ---
+#[rawenum(i8, u8, i64, saturating)]
+#[derive(Debug, PartialEq)]
+#[repr(i64)]
+enum LargeValueEnum {
+    Small = 5,
+    Big = 1000,
+    Negative = -1000,
+    Huge = i64::MAX,
+}
+
+#[test]
+fn test_large_value_enum_saturating() {
+    // Saturating into i8
+    assert_eq!(LargeValueEnum::Small.to_i8_saturating(), 5);
+    assert_eq!(LargeValueEnum::Big.to_i8_saturating(), i8::MAX);
+    assert_eq!(LargeValueEnum::Negative.to_i8_saturating(), i8::MIN);
+    assert_eq!(LargeValueEnum::Huge.to_i8_saturating(), i8::MAX);
+
+    // Saturating into u8
+    assert_eq!(LargeValueEnum::Small.to_u8_saturating(), 5);
+    assert_eq!(LargeValueEnum::Big.to_u8_saturating(), u8::MAX);
+    assert_eq!(LargeValueEnum::Negative.to_u8_saturating(), 0);
+    assert_eq!(LargeValueEnum::Huge.to_u8_saturating(), u8::MAX);
+
+    // Every discriminant fits in i64
+    assert_eq!(LargeValueEnum::Negative.to_i64_saturating(), -1000);
+    assert_eq!(LargeValueEnum::Huge.to_i64_saturating(), i64::MAX);
+}
+
+// --- Test Case 21: Tracing unmatched values through a hook ---
+mod trace_hook {
+    use rawenum::rawenum;
+    use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+
+    pub static UNMATCHED_COUNT: AtomicUsize = AtomicUsize::new(0);
+    pub static LAST_UNMATCHED: AtomicI32 = AtomicI32::new(0);
+
+    pub fn record_unmatched(value: i32) {
+        UNMATCHED_COUNT.fetch_add(1, Ordering::SeqCst);
+        LAST_UNMATCHED.store(value, Ordering::SeqCst);
+    }
+
+    #[rawenum(i32, trace = self::record_unmatched)]
+    #[derive(Debug, PartialEq)]
+    pub enum TracedEnum {
+        A = 1,
+        B = 2,
+    }
+}
+
+#[test]
+fn test_traced_enum_i32() {
+    use std::sync::atomic::Ordering;
+    use trace_hook::{LAST_UNMATCHED, TracedEnum, UNMATCHED_COUNT};
+
+    // Matched values don't call the hook
+    assert_eq!(TracedEnum::from_i32(1), Some(TracedEnum::A));
+    assert_eq!(TracedEnum::from_i32(2), Some(TracedEnum::B));
+    assert_eq!(UNMATCHED_COUNT.load(Ordering::SeqCst), 0);
+
+    // Unmatched values are observed by the hook, and still return None
+    assert_eq!(TracedEnum::from_i32(42), None);
+    assert_eq!(UNMATCHED_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(LAST_UNMATCHED.load(Ordering::SeqCst), 42);
+
+    assert_eq!(TracedEnum::from_i32(-7), None);
+    assert_eq!(UNMATCHED_COUNT.load(Ordering::SeqCst), 2);
+    assert_eq!(LAST_UNMATCHED.load(Ordering::SeqCst), -7);
+}
+
+// --- Test Case 22: Narrowing signed and unsigned repr enums into u8 and i8 ---
+#[rawenum(u8, i8)]
+#[derive(Debug, PartialEq)]
+#[repr(i64)]
+enum SignedReprEnum {
+    MinusOne = -1,       // 255 as u8, -1 as i8
+    Big = 0x1_0000_0080, // 0x80 as u8, -128 as i8
+}
+
+#[rawenum(u8, i8)]
+#[derive(Debug, PartialEq)]
+#[repr(u64)]
+enum UnsignedReprEnum {
+    Max = u64::MAX,      // 255 as u8, -1 as i8
+    Big = 0x1_0000_0080, // 0x80 as u8, -128 as i8
+}
+
+#[test]
+fn test_signed_and_unsigned_repr_narrowing() {
+    // Both reprs narrow the same full-width bit patterns to the same values
+    assert_eq!(SignedReprEnum::from_u8(255), Some(SignedReprEnum::MinusOne));
+    assert_eq!(UnsignedReprEnum::from_u8(255), Some(UnsignedReprEnum::Max));
+    assert_eq!(SignedReprEnum::from_i8(-1), Some(SignedReprEnum::MinusOne));
+    assert_eq!(UnsignedReprEnum::from_i8(-1), Some(UnsignedReprEnum::Max));
+
+    assert_eq!(SignedReprEnum::from_u8(0x80), Some(SignedReprEnum::Big));
+    assert_eq!(UnsignedReprEnum::from_u8(0x80), Some(UnsignedReprEnum::Big));
+    assert_eq!(SignedReprEnum::from_i8(-128), Some(SignedReprEnum::Big));
+    assert_eq!(UnsignedReprEnum::from_i8(-128), Some(UnsignedReprEnum::Big));
+
+    assert_eq!(SignedReprEnum::from_u8(0), None);
+    assert_eq!(UnsignedReprEnum::from_i8(0), None);
+}
+
+// --- Test Case 23: Const-evaluated distinctness checks ---
+const BASE: isize = 0x100;
+
+#[rawenum(u8, i32, assert_distinct)]
+#[derive(Debug, PartialEq)]
+enum DistinctEnum {
+    A = BASE + 1, // 1 as u8
+    B = BASE * 2, // 0 as u8
+    C = 2,
+}
+
+// Enforced at compile time
+const _: () = assert!(DistinctEnum::assert_distinct_u8());
+const _: () = assert!(DistinctEnum::assert_distinct_i32());
+
+// The collision also makes a match arm of from_u8 unreachable, which is linted
+#[allow(unreachable_patterns)]
+mod colliding_enum {
+    use super::BASE;
+    use rawenum::rawenum;
+
+    #[rawenum(u8, i32, assert_distinct)]
+    #[derive(Debug, PartialEq)]
+    pub enum CollidingEnum {
+        Zero = 0,
+        Wrapped = BASE, // 0 as u8, collides with Zero
+    }
+}
+
+#[test]
+fn test_assert_distinct() {
+    assert!(DistinctEnum::assert_distinct_u8());
+    assert!(DistinctEnum::assert_distinct_i32());
+
+    use colliding_enum::CollidingEnum;
+    assert!(!CollidingEnum::assert_distinct_u8());
+    assert!(CollidingEnum::assert_distinct_i32());
+}
+
+// --- Test Case 24: Generating free functions in a module ---
+#[rawenum(u8, i32, module = raw, named, saturating, name_lookup, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+enum ModuleEnum {
+    First = 1,
+    Second = 300,
+}
+
+// These would conflict with generated methods if the macro had generated them on the enum
+impl ModuleEnum {
+    fn from_u8(_value: u8) -> Option<Self> {
+        None
+    }
+
+    fn variant_for_name(_name: &str) -> Option<Self> {
+        None
+    }
+}
+
+#[test]
+fn test_module_enum() {
+    assert_eq!(raw::from_u8(1), Some(ModuleEnum::First));
+    assert_eq!(raw::from_u8(44), Some(ModuleEnum::Second)); // 300 as u8 is 44
+    assert_eq!(raw::from_i32(300), Some(ModuleEnum::Second));
+    assert_eq!(raw::from_i32(2), None);
+    assert_eq!(raw::from_i32_named(1), Some((ModuleEnum::First, "First")));
+    assert_eq!(raw::variant_for_name("Second"), Some(ModuleEnum::Second));
+    assert_eq!(raw::to_u8_saturating(&ModuleEnum::Second), u8::MAX);
+
+    // Only the hand-written methods exist on the enum itself
+    assert_eq!(ModuleEnum::from_u8(1), None);
+    assert_eq!(ModuleEnum::variant_for_name("First"), None);
+}
+
+// --- Test Case 25: Slice helpers for filtering raw values ---
+#[rawenum(i32, u8, slice_helpers)]
+#[derive(Debug, PartialEq)]
+enum PacketKind {
+    Data = 1,
+    Ack = 2,
+    Nack = -1, // -1 as u8 is 255
+}
+
+#[test]
+fn test_packet_kind_slice_helpers() {
+    // The first value that matches a variant wins
+    assert_eq!(PacketKind::from_any_i32(&[7, 2, 1]), Some(PacketKind::Ack));
+    assert_eq!(PacketKind::from_any_i32(&[-1]), Some(PacketKind::Nack));
+    assert_eq!(PacketKind::from_any_u8(&[0, 255]), Some(PacketKind::Nack));
+    assert_eq!(PacketKind::from_any_i32(&[7, 8, 9]), None); // No match
+    assert_eq!(PacketKind::from_any_i32(&[]), None); // Empty slice
+
+    assert!(PacketKind::Data.any_matches_i32(&[5, 1]));
+    assert!(!PacketKind::Data.any_matches_i32(&[5, 2]));
+    assert!(PacketKind::Nack.any_matches_u8(&[255]));
+    assert!(!PacketKind::Nack.any_matches_i32(&[255]));
+    assert!(!PacketKind::Ack.any_matches_i32(&[])); // Empty slice
+}
+
+#[rawenum(u8, module = packet_raw, slice_helpers)]
+#[derive(Debug, PartialEq)]
+enum ModulePacketKind {
+    Data = 1,
+    Ack = 2,
+}
+
+#[test]
+fn test_module_packet_kind_slice_helpers() {
+    // The helpers call the sibling free functions in module mode
+    assert_eq!(
+        packet_raw::from_any_u8(&[9, 2]),
+        Some(ModulePacketKind::Ack)
+    );
+    assert!(packet_raw::any_matches_u8(&ModulePacketKind::Data, &[1]));
+}
+
+// The primary type is the first one requested, in both the inherent and the module mode
+#[rawenum(i32, u8, primary)]
+#[derive(Debug, PartialEq)]
+enum PrimaryKind {
+    Data = 1,
+    Ack = 2,
+}
+
+#[rawenum(u8, module = primary_raw, primary)]
+#[derive(Debug, PartialEq)]
+enum ModulePrimaryKind {
+    Data = 1,
+    Ack = 2,
+}
+
+#[test]
+fn test_primary_kind_primary() {
+    assert_eq!(PrimaryKind::RAWENUM_PRIMARY, "i32");
+    assert_eq!(primary_raw::RAWENUM_PRIMARY, "u8");
+    assert_eq!(PrimaryKind::from_i32(2), Some(PrimaryKind::Ack));
+    assert_eq!(primary_raw::from_u8(1), Some(ModulePrimaryKind::Data));
+
+    // Usable in const contexts
+    const PRIMARY: &str = PrimaryKind::RAWENUM_PRIMARY;
+    assert_eq!(PRIMARY.len(), 3);
+}
+
+// --- Test Case 26: C type aliases for FFI ---
+#[rawenum(c_int, c_uint, c_char, saturating)]
+#[derive(Debug, PartialEq)]
+enum FfiStatus {
+    Ok = 0,
+    Again = 11,
+    Failed = -1, // -1 as c_uint is c_uint::MAX
+    Large = 300, // Saturates to the c_char range
+}
+
+#[test]
+fn test_ffi_status_c_aliases() {
+    use std::ffi::{c_char, c_int, c_uint};
+
+    assert_eq!(FfiStatus::from_c_int(0 as c_int), Some(FfiStatus::Ok));
+    assert_eq!(FfiStatus::from_c_int(-1 as c_int), Some(FfiStatus::Failed));
+    assert_eq!(FfiStatus::from_c_int(12 as c_int), None);
+    assert_eq!(FfiStatus::from_c_uint(c_uint::MAX), Some(FfiStatus::Failed));
+    assert_eq!(FfiStatus::from_c_char(11 as c_char), Some(FfiStatus::Again));
+
+    assert_eq!(FfiStatus::Large.to_c_int_saturating(), 300);
+    assert_eq!(FfiStatus::Failed.to_c_uint_saturating(), 0);
+    assert_eq!(FfiStatus::Large.to_c_char_saturating(), c_char::MAX);
+}
+
+// --- Test Case 27: Overriding the value a variant is matched by ---
+#[rawenum(u8, i32, named, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+enum QuirkyOpcode {
+    Nop = 0,
+    #[rawenum(wire = 0xFF)]
+    Halt = 1,
+    #[rawenum(wire = -2)]
+    Reset = 2,
+}
+
+#[test]
+fn test_quirky_opcode_wire_values() {
+    assert_eq!(QuirkyOpcode::from_u8(0xFF), Some(QuirkyOpcode::Halt));
+    assert_eq!(QuirkyOpcode::from_i32(0xFF), Some(QuirkyOpcode::Halt));
+    assert_eq!(QuirkyOpcode::from_u8(254), Some(QuirkyOpcode::Reset)); // -2 as u8 is 254
+    assert_eq!(QuirkyOpcode::from_i32(-2), Some(QuirkyOpcode::Reset));
+    assert_eq!(
+        QuirkyOpcode::from_u8_named(0xFF),
+        Some((QuirkyOpcode::Halt, "Halt"))
+    );
+    assert_eq!(QuirkyOpcode::from_u8(0), Some(QuirkyOpcode::Nop));
+
+    // The discriminants no longer match
+    assert_eq!(QuirkyOpcode::from_u8(1), None);
+    assert_eq!(QuirkyOpcode::from_i32(2), None);
+
+    // The reverse conversions produce the wire values
+    assert_eq!(QuirkyOpcode::Halt.to_u8(), 0xFF);
+    assert_eq!(QuirkyOpcode::Reset.to_i32(), -2);
+    assert_eq!(QuirkyOpcode::Nop.to_i32(), 0);
+
+    // The discriminants themselves are unchanged
+    assert_eq!(QuirkyOpcode::Halt as u8, 1);
+    assert_eq!(QuirkyOpcode::Reset as u8, 2);
+}
+
+// --- Test Case 28: Generating into an existing impl block ---
+#[derive(Debug, PartialEq)]
+enum HandWrittenEnum {
+    Low = 1,
+    Mid = 5,
+    High = 300,
+}
+
+#[rawenum(u8, i32, variants(Low, Mid, High), named, roundtrip_test)]
+impl HandWrittenEnum {
+    fn is_high(&self) -> bool {
+        *self == Self::High
+    }
+}
+
+#[test]
+fn test_hand_written_enum_impl_attached() {
+    // The generated methods live alongside the hand-written ones
+    assert_eq!(HandWrittenEnum::from_u8(5), Some(HandWrittenEnum::Mid));
+    assert_eq!(HandWrittenEnum::from_u8(44), Some(HandWrittenEnum::High)); // 300 as u8 is 44
+    assert_eq!(HandWrittenEnum::from_i32(300), Some(HandWrittenEnum::High));
+    assert_eq!(HandWrittenEnum::from_i32(2), None);
+    assert_eq!(
+        HandWrittenEnum::from_i32_named(1),
+        Some((HandWrittenEnum::Low, "Low"))
+    );
+    assert!(HandWrittenEnum::from_i32(300).unwrap().is_high());
+}
+
+// --- Test Case 29: Wire values written in different literal bases ---
+#[rawenum(u8, i16)]
+#[derive(Debug, PartialEq)]
+enum MixedBaseWire {
+    #[rawenum(wire = 0xF0)]
+    Hex,
+    #[rawenum(wire = 0b1010)]
+    Binary,
+    #[rawenum(wire = 0o17)]
+    Octal,
+    #[rawenum(wire = 1_00)]
+    Decimal,
+    #[rawenum(wire = -0x2)]
+    NegativeHex,
+}
+
+#[test]
+fn test_mixed_base_wire() {
+    assert_eq!(MixedBaseWire::from_u8(240), Some(MixedBaseWire::Hex));
+    assert_eq!(MixedBaseWire::from_u8(10), Some(MixedBaseWire::Binary));
+    assert_eq!(MixedBaseWire::from_u8(15), Some(MixedBaseWire::Octal));
+    assert_eq!(MixedBaseWire::from_u8(100), Some(MixedBaseWire::Decimal));
+    assert_eq!(
+        MixedBaseWire::from_u8(254),
+        Some(MixedBaseWire::NegativeHex)
+    );
+    assert_eq!(
+        MixedBaseWire::from_i16(-2),
+        Some(MixedBaseWire::NegativeHex)
+    );
+    assert_eq!(MixedBaseWire::from_i16(0xF0), Some(MixedBaseWire::Hex));
+    assert_eq!(MixedBaseWire::from_u8(0), None); // The discriminant of `Hex` no longer matches
+}
+
+// --- Test Case 30: Suffixed method names ---
+#[rawenum(u8, i32, suffix = "raw", named, opt_helpers, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+enum SuffixedEnum {
+    Small = 3,
+    Big = 400,
+}
+
+// A hand-written constructor that would collide with an unsuffixed `from_u8`
+impl SuffixedEnum {
+    fn from_u8(value: u8) -> Self {
+        if value < 100 { Self::Small } else { Self::Big }
+    }
+}
+
+#[test]
+fn test_suffixed_enum() {
+    assert_eq!(SuffixedEnum::from_u8_raw(3), Some(SuffixedEnum::Small));
+    assert_eq!(SuffixedEnum::from_u8_raw(144), Some(SuffixedEnum::Big)); // 400 as u8 is 144
+    assert_eq!(SuffixedEnum::from_i32_raw(400), Some(SuffixedEnum::Big));
+    assert_eq!(SuffixedEnum::from_i32_raw(4), None);
+    assert_eq!(
+        SuffixedEnum::from_i32_raw_named(3),
+        Some((SuffixedEnum::Small, "Small"))
+    );
+    assert_eq!(
+        SuffixedEnum::from_opt_u8_raw(Some(3)),
+        Some(SuffixedEnum::Small)
+    );
+
+    // The hand-written constructor is untouched
+    assert_eq!(SuffixedEnum::from_u8(200), SuffixedEnum::Big);
+}
+
+// --- Test Case 31: Decoding a tag field from packed words ---
+#[rawenum(u32, u16, decode(shift = 4, mask = 0xF))]
+#[derive(Debug, PartialEq)]
+enum PackedOpcode {
+    Load = 1,
+    Store = 2,
+    Jump = 0xF,
+}
+
+#[test]
+fn test_packed_opcode_decode() {
+    // The tag is bits 4..8, the rest of the word is the payload
+    assert_eq!(
+        PackedOpcode::decode_u32(0x1234_5625),
+        Some((PackedOpcode::Store, 0x1234_5605))
+    );
+    assert_eq!(
+        PackedOpcode::decode_u32(0xFFFF_FFFF),
+        Some((PackedOpcode::Jump, 0xFFFF_FF0F))
+    );
+    assert_eq!(
+        PackedOpcode::decode_u16(0x0010),
+        Some((PackedOpcode::Load, 0))
+    );
+    assert_eq!(PackedOpcode::decode_u32(0x1234_5675), None); // Tag 7 matches no variant
+    assert_eq!(PackedOpcode::decode_u32(0x0000_0005), None); // Tag 0 matches no variant
+}
+
+// --- Test Case 32: Discriminants defined by other enums' values ---
+mod protocol {
+    pub enum Base {
+        Control = 0x10,
+        Data = 0x20,
+    }
+}
+
+#[allow(dead_code)]
+enum Offset {
+    Small = 1,
+    Large = 0x100,
+}
+
+#[rawenum(i32, u8)]
+#[derive(Debug, PartialEq)]
+enum DerivedEnum {
+    Control = protocol::Base::Control as isize,
+    ControlAck,                                 // Implicit discriminant (0x11)
+    Data = self::protocol::Base::Data as isize, // Qualified with `self::`
+    DataFar = protocol::Base::Data as isize + Offset::Large as isize,
+    DataNext = protocol::Base::Data as isize + Offset::Small as isize,
+}
+
+#[test]
+fn test_derived_enum_cross_enum_discriminants() {
+    assert_eq!(DerivedEnum::from_i32(0x10), Some(DerivedEnum::Control));
+    assert_eq!(DerivedEnum::from_i32(0x11), Some(DerivedEnum::ControlAck));
+    assert_eq!(DerivedEnum::from_i32(0x20), Some(DerivedEnum::Data));
+    assert_eq!(DerivedEnum::from_i32(0x120), Some(DerivedEnum::DataFar));
+    assert_eq!(DerivedEnum::from_i32(0x21), Some(DerivedEnum::DataNext));
+    assert_eq!(DerivedEnum::from_i32(Offset::Small as i32), None);
+
+    // 0x120 as u8 is 0x20, which `Data` claims first
+    assert_eq!(DerivedEnum::from_u8(0x20), Some(DerivedEnum::Data));
+    assert_eq!(DerivedEnum::from_u8(0x11), Some(DerivedEnum::ControlAck));
+}
+
+// --- Test Case 33: Reusable type lists ---
+rawenum::rawenum_types!(ProtocolTypes = i32, u8);
+
+#[rawenum(use ProtocolTypes)]
+#[derive(Debug, PartialEq)]
+enum ProtocolCommand {
+    Read = 1,
+    Write = 0x101,
+}
+
+#[rawenum(named, use ProtocolTypes)]
+#[derive(Debug, PartialEq)]
+enum ProtocolStatus {
+    Ok = 0,
+    Busy = -1,
+}
+
+mod nested_protocol {
+    use rawenum::rawenum;
+
+    // Type lists follow the scoping rules of `macro_rules!` macros, so they're visible
+    // in modules that follow them
+    #[rawenum(use ProtocolTypes)]
+    #[derive(Debug, PartialEq)]
+    pub enum Ack {
+        Yes = 1,
+    }
+}
+
+#[test]
+fn test_protocol_type_list() {
+    assert_eq!(
+        ProtocolCommand::from_i32(0x101),
+        Some(ProtocolCommand::Write)
+    );
+    assert_eq!(ProtocolCommand::from_u8(1), Some(ProtocolCommand::Read)); // 0x101 as u8 is 1
+    assert_eq!(ProtocolStatus::from_u8(255), Some(ProtocolStatus::Busy));
+    assert_eq!(
+        ProtocolStatus::from_i32_named(0),
+        Some((ProtocolStatus::Ok, "Ok"))
+    );
+    assert_eq!(
+        nested_protocol::Ack::from_u8(1),
+        Some(nested_protocol::Ack::Yes)
+    );
+}
+
+// --- Test Case 34: Total conversions for internal enums ---
+#[rawenum(u8, i32, total, roundtrip_test, opt_helpers, slice_helpers)]
+#[derive(Debug, PartialEq)]
+enum InternalState {
+    Idle = 0,
+    Running = 1,
+    Done = 7,
+}
+
+#[test]
+fn test_internal_state_total() {
+    // The conversions return `Self` directly
+    assert_eq!(InternalState::from_u8(0), InternalState::Idle);
+    assert_eq!(InternalState::from_i32(7), InternalState::Done);
+
+    // Helpers built on the conversions follow their shape
+    assert_eq!(
+        InternalState::from_opt_u8(Some(1)),
+        Some(InternalState::Running)
+    );
+    assert_eq!(InternalState::from_opt_u8(None), None);
+    assert_eq!(
+        InternalState::from_any_i32(&[7, 0]),
+        Some(InternalState::Done)
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid discriminant 3")]
+fn test_internal_state_total_panics() {
+    let _ = InternalState::from_u8(3);
+}
+
+// --- Test Case 35: Const predicates for match guards ---
+#[rawenum(i32, bool, contains)]
+#[derive(Debug, PartialEq)]
+enum Syscall {
+    Read = 0,
+    Write = 1,
+    Exit = 60,
+}
+
+#[test]
+fn test_syscall_contains() {
+    // Usable in const contexts
+    const {
+        assert!(Syscall::contains_i32(60));
+        assert!(!Syscall::contains_i32(2));
+    }
+
+    let describe = |raw: i32| match raw {
+        v if Syscall::contains_i32(v) => "syscall",
+        v if v < 0 => "negative",
+        _ => "unknown",
+    };
+    assert_eq!(describe(0), "syscall");
+    assert_eq!(describe(60), "syscall");
+    assert_eq!(describe(-1), "negative");
+    assert_eq!(describe(2), "unknown");
+
+    assert!(Syscall::contains_bool(false));
+    assert!(Syscall::contains_bool(true));
+    assert_eq!(Syscall::from_i32(60), Some(Syscall::Exit));
+    assert_eq!(Syscall::from_bool(false), Some(Syscall::Read));
+    assert_eq!(Syscall::from_bool(true), Some(Syscall::Write));
+}
+
+// --- Test Case 36: Contiguous ordinals for dispatch tables ---
+// Sparse discriminants, including ones too large to index a table by
+#[rawenum(i64, ordinals)]
+#[derive(Debug, PartialEq)]
+#[repr(i64)]
+enum SparseEnum {
+    Small = 5,
+    Big = 1000,
+    Negative = -1000,
+    Huge = i64::MAX,
+}
+
+#[test]
+fn test_sparse_enum_ordinals() {
+    // The ordinals are contiguous even though the discriminants are sparse
+    assert_eq!(SparseEnum::ORDINALS, [0, 1, 2, 3]);
+    assert_eq!(SparseEnum::Small.to_ordinal(), 0);
+    assert_eq!(SparseEnum::Big.to_ordinal(), 1);
+    assert_eq!(SparseEnum::Negative.to_ordinal(), 2);
+    assert_eq!(SparseEnum::Huge.to_ordinal(), 3);
+
+    // A dispatch table indexed by ordinal
+    const ORDINAL: usize = SparseEnum::Huge.to_ordinal();
+    let handlers: [fn(i64) -> i64; SparseEnum::ORDINALS.len()] =
+        [|x| x, |x| x * 2, |x| -x, |x| x - 1];
+    assert_eq!(handlers[SparseEnum::Big.to_ordinal()](21), 42);
+    assert_eq!(handlers[ORDINAL](1), 0);
+}
+
+// --- Test Case 37: Plugging into generic code bounded on `TryFrom` ---
+#[rawenum(u8, u16, impl_try_from)]
+#[derive(Debug, PartialEq)]
+enum FrameKind {
+    Data = 0,
+    Ack = 1,
+    Ping = 4,
+}
+
+// A generic deserializer that only knows the bound
+fn decode_all<T: TryFrom<u8>>(bytes: &[u8]) -> Option<Vec<T>> {
+    bytes.iter().map(|&byte| T::try_from(byte).ok()).collect()
+}
+
+#[test]
+fn test_frame_kind_impl_try_from() {
+    assert_eq!(
+        decode_all::<FrameKind>(&[0, 4, 1]),
+        Some(vec![FrameKind::Data, FrameKind::Ping, FrameKind::Ack])
+    );
+    assert_eq!(decode_all::<FrameKind>(&[0, 2]), None);
+    assert_eq!(
+        FrameKind::try_from(5u16),
+        Err(FrameKindParseError::OutOfRange(5))
+    );
+}
+
+// --- Test Case 38: Converting into the raw types for encoders ---
+#[rawenum(u8, i32, impl_from)]
+#[derive(Debug, PartialEq)]
+enum MessageType {
+    Hello = 1,
+    #[rawenum(wire = 0x80)]
+    Goodbye = 2,
+}
+
+#[rawenum(u8, i16, impl_from, lossy)]
+#[derive(Debug, PartialEq)]
+enum WideMessageType {
+    Small = 1,
+    Wide = 0x1FF,
+}
+
+#[test]
+fn test_message_type_into() {
+    let mut buf: Vec<u8> = vec![0xAA];
+    buf.push(MessageType::Hello.into());
+    buf.push(MessageType::Goodbye.into());
+    assert_eq!(buf, [0xAA, 1, 0x80]);
+    assert_eq!(i32::from(MessageType::Goodbye), 0x80);
+
+    // With `lossy`, the values are truncated like the `to_*` methods
+    assert_eq!(u8::from(WideMessageType::Wide), 0xFF);
+    assert_eq!(i16::from(WideMessageType::Wide), 0x1FF);
+    assert_eq!(u8::from(WideMessageType::Small), 1);
+}
+
+// --- Test Case 39: Const conversions ---
+#[rawenum(u8, u32, bool, const)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RegisterMode {
+    Disabled = 0,
+    Enabled = 1,
+    Burst = 0x80,
+}
+
+// Decoded at compile time
+const BURST: Option<RegisterMode> = RegisterMode::from_u32(0x80);
+const MODES: [Option<RegisterMode>; 3] = {
+    let mut modes = [None; 3];
+    let mut i = 0;
+    while i < modes.len() {
+        modes[i] = RegisterMode::from_u8(i as u8);
+        i += 1;
+    }
+    modes
+};
+
+#[test]
+fn test_register_mode_const() {
+    assert_eq!(BURST, Some(RegisterMode::Burst));
+    assert_eq!(
+        MODES,
+        [
+            Some(RegisterMode::Disabled),
+            Some(RegisterMode::Enabled),
+            None
+        ]
+    );
+    const { assert!(RegisterMode::from_bool(true).is_some()) };
+}
+
+// --- Test Case 40: Strict matching without wrapping ---
+#[rawenum(
+    u8,
+    i8,
+    i16,
+    bool,
+    strict,
+    named,
+    contains,
+    roundtrip_test,
+    property_test
+)]
+#[derive(Debug, PartialEq)]
+#[repr(i16)]
+enum WideRegister {
+    // Declared first, so it would shadow `Zero` in `from_u8` if it wrapped to 0
+    Big = 256,
+    Zero = 0,
+    Negative = -1,
+    One = 1,
+}
+
+#[test]
+fn test_wide_register_strict() {
+    // 256 doesn't fit in u8, so 0 only matches `Zero`
+    assert_eq!(WideRegister::from_u8(0), Some(WideRegister::Zero));
+    assert_eq!(
+        WideRegister::from_u8_named(0),
+        Some((WideRegister::Zero, "Zero"))
+    );
+    // -1 doesn't fit in u8 either, so it doesn't match 255
+    assert_eq!(WideRegister::from_u8(255), None);
+    assert!(!WideRegister::contains_u8(255));
+
+    assert_eq!(WideRegister::from_i8(-1), Some(WideRegister::Negative));
+    assert_eq!(WideRegister::from_i8(0), Some(WideRegister::Zero));
+    assert_eq!(WideRegister::from_i16(256), Some(WideRegister::Big));
+    assert_eq!(WideRegister::from_i16(0), Some(WideRegister::Zero));
+
+    // `bool` represents 0 and 1
+    assert_eq!(WideRegister::from_bool(false), Some(WideRegister::Zero));
+    assert_eq!(WideRegister::from_bool(true), Some(WideRegister::One));
+}
+
+// --- Test Case 41: Falling back to a catch-all variant ---
+#[rawenum(u8, i32, named, try_from, opt_helpers, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+enum NetOpcode {
+    Connect = 1,
+    Send = 2,
+    #[rawenum(wire = 0x10)]
+    Close = 3,
+    #[rawenum(catch_all)]
+    Unknown = 0xFF,
+}
+
+#[test]
+fn test_net_opcode_catch_all() {
+    assert_eq!(NetOpcode::from_u8(1), NetOpcode::Connect);
+    assert_eq!(NetOpcode::from_u8(0x10), NetOpcode::Close);
+    assert_eq!(NetOpcode::from_u8(0xFF), NetOpcode::Unknown);
+    // Any other value falls back to the catch-all variant
+    assert_eq!(NetOpcode::from_u8(3), NetOpcode::Unknown);
+    assert_eq!(NetOpcode::from_i32(-7), NetOpcode::Unknown);
+
+    // The other helpers follow the conversions
+    assert_eq!(NetOpcode::from_u8_named(9), None);
+    assert_eq!(NetOpcode::from_opt_u8(Some(9)), Some(NetOpcode::Unknown));
+    assert_eq!(NetOpcode::try_from(9u8), Ok(NetOpcode::Unknown));
+}
+
+// --- Test Case 42: A catch-all variant holding the unmatched value ---
+#[rawenum(u32, u8, named, expect, iter, impl_from, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+#[repr(u32)]
+enum Extension {
+    ServerName = 0,
+    MaxFragmentLength,
+    #[rawenum(wire = 0xF1)]
+    Renegotiation = 2,
+    KeyShare = 51,
+    #[rawenum(catch_all)]
+    Other(u32) = 0xFFFF_FFFF,
+}
+
+#[test]
+fn test_extension_catch_all_payload() {
+    assert_eq!(Extension::from_u32(0), Extension::ServerName);
+    assert_eq!(Extension::from_u32(1), Extension::MaxFragmentLength);
+    assert_eq!(Extension::from_u32(0xF1), Extension::Renegotiation);
+    assert_eq!(Extension::from_u32(51), Extension::KeyShare);
+    // Unmatched values are kept in the catch-all variant
+    assert_eq!(Extension::from_u32(43), Extension::Other(43));
+    assert_eq!(Extension::from_u8(2), Extension::Other(2));
+    assert_eq!(Extension::expect_u32(7), Extension::Other(7));
+    assert_eq!(Extension::from_u32_named(43), None);
+
+    // And converted back to the raw values
+    assert_eq!(Extension::KeyShare.to_u32(), 51);
+    assert_eq!(Extension::Renegotiation.to_u32(), 0xF1);
+    assert_eq!(Extension::Other(43).to_u32(), 43);
+    assert_eq!(u32::from(Extension::from_u32(1234)), 1234);
+
+    // Only the unit variants are iterated
+    assert_eq!(Extension::iter().count(), 4);
+}
+
+// --- Test Case 43: Aliases decoding several values to one variant ---
+#[rawenum(u8, i16, named, contains, try_from, roundtrip_test, property_test)]
+#[derive(Debug, PartialEq)]
+enum LegacyCommand {
+    Read = 1,
+    #[rawenum(alias = 0x7F, alias = 0xFF)]
+    Write = 2,
+    #[rawenum(wire = 0x10, alias = -1)]
+    Reset = 3,
+}
+
+#[test]
+fn test_legacy_command_aliases() {
+    assert_eq!(LegacyCommand::from_u8(2), Some(LegacyCommand::Write));
+    assert_eq!(LegacyCommand::from_u8(0x7F), Some(LegacyCommand::Write));
+    assert_eq!(LegacyCommand::from_u8(0xFF), Some(LegacyCommand::Write));
+    assert_eq!(LegacyCommand::from_i16(0xFF), Some(LegacyCommand::Write));
+    assert_eq!(
+        LegacyCommand::from_u8_named(0x7F),
+        Some((LegacyCommand::Write, "Write"))
+    );
+    assert!(LegacyCommand::contains_u8(0x7F));
+
+    // Aliases combine with wire overrides
+    assert_eq!(LegacyCommand::from_u8(0x10), Some(LegacyCommand::Reset));
+    assert_eq!(LegacyCommand::from_i16(-1), Some(LegacyCommand::Reset));
+    assert_eq!(LegacyCommand::from_u8(3), None);
+
+    // The aliases extend the range of the values
+    assert_eq!(LegacyCommand::try_from(-1i16), Ok(LegacyCommand::Reset));
+    assert_eq!(
+        LegacyCommand::try_from(0x100i16),
+        Err(LegacyCommandParseError::OutOfRange(0x100))
+    );
+
+    // Converting back uses the variant's own value
+    assert_eq!(LegacyCommand::Write.to_u8(), 2);
+    assert_eq!(LegacyCommand::Reset.to_i16(), 0x10);
+}
+
+// --- Test Case 44: Raw values decoupled from implicit discriminants ---
+#[rawenum(u8, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+enum RegisterField {
+    #[rawenum(value = 0x40)]
+    Enable,
+    #[rawenum(value = 0x80)]
+    Reset,
+    Idle,
+}
+
+#[test]
+fn test_register_field_values() {
+    assert_eq!(RegisterField::from_u8(0x40), Some(RegisterField::Enable));
+    assert_eq!(RegisterField::from_u8(0x80), Some(RegisterField::Reset));
+    // Variants without a value still match their discriminant
+    assert_eq!(RegisterField::from_u8(2), Some(RegisterField::Idle));
+    assert_eq!(RegisterField::from_u8(0), None);
+    assert_eq!(RegisterField::Reset.to_u8(), 0x80);
+    assert_eq!(RegisterField::Reset as u8, 1);
+}
+
+// --- Test Case 45: Skipping internal variants ---
+#[rawenum(u8, i32, named, try_from, roundtrip_test, property_test)]
+#[derive(Debug, PartialEq)]
+enum ParserState {
+    Start = 0,
+    Body = 1,
+    #[rawenum(skip)]
+    Poisoned = 2,
+    End = 3,
+}
+
+#[test]
+fn test_parser_state_skip() {
+    assert_eq!(ParserState::from_u8(0), Some(ParserState::Start));
+    assert_eq!(ParserState::from_i32(3), Some(ParserState::End));
+    // The skipped variant can't be constructed from raw values
+    assert_eq!(ParserState::from_u8(2), None);
+    assert_eq!(ParserState::from_u8_named(2), None);
+    assert_eq!(
+        ParserState::try_from(2u8),
+        Err(ParserStateParseError::NoVariant(2))
+    );
+    // But it still converts to its raw value
+    assert_eq!(ParserState::Poisoned.to_u8(), 2);
+}
+
+// --- Test Case 46: Variants gated behind a cfg ---
+#[rawenum(u8, i32, named, contains, roundtrip_test, saturating, ord_by_value)]
+#[derive(Debug, PartialEq, Eq)]
+enum Feature {
+    Base = 1,
+    #[cfg(any())]
+    Disabled = 2,
+    #[cfg_attr(any(), cfg(any()))]
+    Extended = 3,
+    #[cfg(test)]
+    Enabled = 4,
+}
+
+#[test]
+fn test_feature_cfg() {
+    assert_eq!(Feature::from_u8(1), Some(Feature::Base));
+    // The compiled-out variant isn't matched
+    assert_eq!(Feature::from_u8(2), None);
+    assert_eq!(Feature::from_u8_named(2), None);
+    assert!(!Feature::contains_u8(2));
+    assert_eq!(Feature::from_i32(3), Some(Feature::Extended));
+    assert_eq!(Feature::from_u8(4), Some(Feature::Enabled));
+    assert_eq!(Feature::Enabled.to_i32(), 4);
+}
+
+// --- Test Case 47: Types inferred from the enum's repr ---
+#[rawenum]
+#[repr(i16)]
+#[derive(Debug, PartialEq)]
+enum Temperature {
+    Freezing = -40,
+    Mild = 20,
+}
+
+#[rawenum(repr, u8, named)]
+#[repr(u16)]
+#[derive(Debug, PartialEq)]
+enum PortClass {
+    WellKnown = 80,
+    Registered = 8080,
+}
+
+#[test]
+fn test_repr_inferred_types() {
+    assert_eq!(Temperature::from_i16(-40), Some(Temperature::Freezing));
+    assert_eq!(Temperature::Mild.to_i16(), 20);
+    assert_eq!(PortClass::from_u16(8080), Some(PortClass::Registered));
+    assert_eq!(
+        PortClass::from_u16_named(80),
+        Some((PortClass::WellKnown, "WellKnown"))
+    );
+    assert_eq!(PortClass::from_u8(80), Some(PortClass::WellKnown));
+}
+
+// --- Test Case 48: Type group keywords ---
+#[rawenum(all)]
+#[derive(Debug, PartialEq)]
+enum Priority {
+    Low = 1,
+    High = 2,
+}
+
+#[rawenum(u8, signed, named)]
+#[derive(Debug, PartialEq)]
+enum Direction {
+    Left = -1,
+    Right = 1,
+}
+
+#[test]
+fn test_type_groups() {
+    assert_eq!(Priority::from_u8(1), Some(Priority::Low));
+    assert_eq!(Priority::from_i16(2), Some(Priority::High));
+    assert_eq!(Priority::from_u64(2), Some(Priority::High));
+    assert_eq!(Priority::High.to_i64(), 2);
+    assert_eq!(Direction::from_i8(-1), Some(Direction::Left));
+    assert_eq!(
+        Direction::from_i64_named(1),
+        Some((Direction::Right, "Right"))
+    );
+    assert_eq!(Direction::from_u8(255), Some(Direction::Left));
+}
+
+// --- Test Case 49: Pointer-sized types ---
+#[rawenum(usize, isize, strict, roundtrip_test)]
+#[repr(i64)]
+#[derive(Debug, PartialEq)]
+enum Slot {
+    Negative = -1,
+    First = 0,
+    Last = 0xFFFF_FFFF,
+}
+
+#[test]
+fn test_slot_pointer_sized() {
+    assert_eq!(Slot::from_usize(0), Some(Slot::First));
+    assert_eq!(Slot::from_isize(-1), Some(Slot::Negative));
+    // With `strict`, a negative value never matches `usize`
+    assert_eq!(Slot::from_usize(usize::MAX), None);
+    assert_eq!(Slot::Last.to_usize(), 0xFFFF_FFFF);
+    // 0xFFFF_FFFF only fits in `isize` on 64-bit targets
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(Slot::from_isize(0xFFFF_FFFF), Some(Slot::Last));
+}
+
+// --- Test Case 50: 128-bit types ---
+#[rawenum(u128, i128, roundtrip_test)]
+#[repr(u128)]
+#[derive(Debug, PartialEq)]
+enum ObjectId {
+    Root = 1,
+    Hashed = 0xDEAD_BEEF_0000_0000_0000_0000_CAFE_F00D,
+}
+
+#[rawenum(i128, strict_types)]
+#[repr(i128)]
+#[derive(Debug, PartialEq)]
+enum FileOffset {
+    Start = -170_141_183_460_469_231_731_687_303_715_884_105_728,
+    End = 170_141_183_460_469_231_731_687_303_715_884_105_727,
+}
+
+#[test]
+fn test_128_bit_types() {
+    assert_eq!(ObjectId::from_u128(1), Some(ObjectId::Root));
+    assert_eq!(
+        ObjectId::from_u128(0xDEAD_BEEF_0000_0000_0000_0000_CAFE_F00D),
+        Some(ObjectId::Hashed)
+    );
+    assert_eq!(
+        ObjectId::Hashed.to_u128(),
+        0xDEAD_BEEF_0000_0000_0000_0000_CAFE_F00D
+    );
+    assert_eq!(FileOffset::from_i128(i128::MIN), Some(FileOffset::Start));
+    assert_eq!(FileOffset::End.to_i128(), i128::MAX);
+}
+
+// --- Test Case 51: NonZero conversions ---
+#[rawenum(u8, i32, nonzero, const)]
+#[derive(Debug, PartialEq)]
+enum Channel {
+    Primary = 1,
+    Secondary = 2,
+}
+
+#[test]
+fn test_channel_nonzero() {
+    use std::num::{NonZeroI32, NonZeroU8};
+
+    const PRIMARY: Option<Channel> = Channel::from_nonzero_u8(NonZeroU8::MIN);
+    assert_eq!(PRIMARY, Some(Channel::Primary));
+    let field: Option<NonZeroU8> = NonZeroU8::new(2);
+    assert_eq!(
+        field.and_then(Channel::from_nonzero_u8),
+        Some(Channel::Secondary)
+    );
+    assert_eq!(Channel::from_nonzero_i32(NonZeroI32::new(3).unwrap()), None);
+}
+
+// --- Test Case 52: Unchecked conversions ---
+#[rawenum(u8, i32, unchecked, const)]
+#[repr(u8)]
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    Load = 0x10,
+    Store = 0x20,
+}
+
+#[test]
+fn test_instruction_unchecked() {
+    // SAFETY: 0x10 is the discriminant of `Load`
+    const LOAD: Instruction = unsafe { Instruction::from_u8_unchecked(0x10) };
+    assert_eq!(LOAD, Instruction::Load);
+    for value in [0x10, 0x20] {
+        assert!(Instruction::from_u8(value).is_some());
+        // SAFETY: `from_u8` checked that the value is a discriminant
+        let instruction = unsafe { Instruction::from_u8_unchecked(value) };
+        assert_eq!(Some(instruction), Instruction::from_u8(value));
+    }
+}
+
+// --- Test Case 53: Result-based conversions ---
+#[rawenum(u8, i32, result)]
+#[derive(Debug, PartialEq)]
+enum MessageKind {
+    Hello = 1,
+    Goodbye = 2,
+}
+
+#[test]
+fn test_message_kind_result() {
+    assert_eq!(MessageKind::try_from_u8(1), Ok(MessageKind::Hello));
+    let error = MessageKind::try_from_i32(7).unwrap_err();
+    assert_eq!(error, MessageKindInvalidRawValue { value: 7 });
+    assert_eq!(
+        error.to_string(),
+        "invalid raw value 7 for `MessageKind`, expected one of 1, 2"
+    );
+    let error: Box<dyn std::error::Error> = Box::new(MessageKind::try_from_u8(9).unwrap_err());
+    assert_eq!(
+        error.to_string(),
+        "invalid raw value 9 for `MessageKind`, expected one of 1, 2"
+    );
+}
+
+// --- Test Case 54: Custom error types ---
+#[derive(Debug, PartialEq)]
+enum ProtoError {
+    BadFrameType(u8),
+    BadFlags(u16),
+}
+
+impl From<FrameTypeInvalidRawValue<u8>> for ProtoError {
+    fn from(error: FrameTypeInvalidRawValue<u8>) -> Self {
+        ProtoError::BadFrameType(error.value)
+    }
+}
+
+impl ProtoError {
+    fn bad_flags(error: FrameFlagsInvalidRawValue<u16>) -> Self {
+        ProtoError::BadFlags(error.value)
+    }
+}
+
+#[rawenum(u8, error = ProtoError)]
+#[derive(Debug, PartialEq)]
+enum FrameType {
+    Data = 0,
+    Headers = 1,
+}
+
+#[rawenum(u16, error = ProtoError, error_fn = ProtoError::bad_flags)]
+#[derive(Debug, PartialEq)]
+enum FrameFlags {
+    EndStream = 0x1,
+    Padded = 0x8,
+}
+
+#[test]
+fn test_custom_error_types() {
+    assert_eq!(FrameType::try_from_u8(1), Ok(FrameType::Headers));
+    assert_eq!(FrameType::try_from_u8(9), Err(ProtoError::BadFrameType(9)));
+    assert_eq!(FrameFlags::try_from_u16(0x8), Ok(FrameFlags::Padded));
+    assert_eq!(
+        FrameFlags::try_from_u16(0x2),
+        Err(ProtoError::BadFlags(0x2))
+    );
+}
+
+// --- Test Case 55: rawenum-core traits ---
+#[rawenum(u8, i64, bool, traits)]
+#[derive(Debug, PartialEq)]
+enum Switch {
+    Off = 0,
+    On = 1,
+}
+
+fn decode_raw<E: rawenum_core::FromRaw<u8>>(bytes: &[u8]) -> Option<E> {
+    E::from_raw(*bytes.first()?)
+}
+
+#[test]
+fn test_switch_traits() {
+    use rawenum_core::{FromRaw, IntoRaw};
+
+    assert_eq!(decode_raw::<Switch>(&[1, 0]), Some(Switch::On));
+    assert_eq!(decode_raw::<Switch>(&[2]), None);
+    assert_eq!(
+        <Switch as FromRaw<bool>>::from_raw(false),
+        Some(Switch::Off)
+    );
+    let raw: i64 = Switch::On.into_raw();
+    assert_eq!(raw, 1);
+}
+
+// --- Test Case 56: Generic conversion entry point ---
+#[rawenum(u8, i32, generic)]
+#[derive(Debug, PartialEq)]
+enum Signal {
+    Stop = 1,
+    Go = 2,
+}
+
+#[test]
+fn test_signal_generic() {
+    assert_eq!(Signal::from_raw(1u8), Some(Signal::Stop));
+    assert_eq!(Signal::from_raw(2i32), Some(Signal::Go));
+    assert_eq!(Signal::from_raw(3i32), None);
+}
+
+// --- Test Case 57: num_enum compatibility ---
+#[rawenum(i32, num_enum)]
+#[repr(u8)]
+#[derive(Debug, PartialEq)]
+enum Color {
+    Red = 1,
+    #[rawenum(alias = 3)]
+    Green = 2,
+}
+
+#[test]
+fn test_color_num_enum() {
+    assert_eq!(Color::NAME, "Color");
+    assert_eq!(Color::try_from_primitive(1), Ok(Color::Red));
+    assert_eq!(Color::try_from(3u8), Ok(Color::Green));
+    let error = Color::try_from(7u8).unwrap_err();
+    assert_eq!(error.number, 7);
+    assert_eq!(
+        error.to_string(),
+        "No discriminant in enum `Color` matches the value `7`"
+    );
+    let raw: u8 = Color::Green.into();
+    assert_eq!(raw, 2);
+    // The requested types are still generated
+    assert_eq!(Color::from_i32(2), Some(Color::Green));
+}
+
+// --- Test Case 58: Inline enum definitions ---
+rawenum_inline! {
+    #[repr(u8)]
+    #[derive(Debug, PartialEq)]
+    pub enum X86Opcode {
+        Nop = 0x90,
+        Ret = 0xC3,
+    } => u8, i32, named
+}
+
+rawenum_inline! {
+    #[repr(u16)]
+    #[derive(Debug, PartialEq)]
+    enum Baud {
+        Slow = 300,
+        Fast = 9600,
+    }
+}
+
+#[test]
+fn test_inline_enums() {
+    assert_eq!(X86Opcode::from_u8(0x90), Some(X86Opcode::Nop));
+    assert_eq!(
+        X86Opcode::from_i32_named(0xC3),
+        Some((X86Opcode::Ret, "Ret"))
+    );
+    assert_eq!(X86Opcode::Ret.to_u8(), 0xC3);
+    // Without arguments, the type is read from the repr
+    assert_eq!(Baud::from_u16(9600), Some(Baud::Fast));
+}
+
+// --- Test Case 59: Module-level attribute ---
+#[rawenum_mod(u8, named)]
+mod message_types {
+    use rawenum::rawenum;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Handshake {
+        Hello = 1,
+        Welcome = 2,
+    }
+
+    // Enums with their own attribute keep it
+    #[rawenum(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum Control {
+        Pause = -1,
+        Resume = 1,
+    }
+
+    pub mod data {
+        #[derive(Debug, PartialEq)]
+        pub enum Chunk {
+            First = 10,
+            Last = 20,
+        }
+    }
+}
+
+#[test]
+fn test_module_level_attribute() {
+    use message_types::{Control, Handshake, data::Chunk};
+
+    assert_eq!(Handshake::from_u8(2), Some(Handshake::Welcome));
+    assert_eq!(
+        Handshake::from_u8_named(1),
+        Some((Handshake::Hello, "Hello"))
+    );
+    assert_eq!(Control::from_i32(-1), Some(Control::Pause));
+    assert_eq!(Chunk::from_u8(20), Some(Chunk::Last));
+}
+
+// --- Test Case 60: Enums defined in another crate ---
+mod foreign {
+    // Stands in for an enum of another crate, which can't be annotated
+    #[derive(Debug, PartialEq)]
+    #[non_exhaustive]
+    pub enum Status {
+        Ok = 0,
+        NotFound = 404,
+        Teapot = 418,
+    }
+}
+
+rawenum_remote! {
+    for foreign::Status as u32, i16 {
+        Ok = 0,
+        NotFound = 404,
+    }
+}
+
+#[test]
+fn test_remote_enum() {
+    assert_eq!(
+        foreign::Status::from_u32(404),
+        Some(foreign::Status::NotFound)
+    );
+    assert_eq!(foreign::Status::from_i16(0), Some(foreign::Status::Ok));
+    // Unlisted variants aren't matched
+    assert_ne!(
+        foreign::Status::from_u32(418),
+        Some(foreign::Status::Teapot)
+    );
+    assert_eq!(foreign::Status::from_u32(1), None);
+}
+
+// --- Test Case 61: Variants read from a file ---
+#[rawenum(u8, u32, values_from = "tests/registers.txt")]
+#[derive(Debug, PartialEq)]
+enum Register {
+    Reset = 0xFF,
+}
+
+#[test]
+fn test_values_from() {
+    assert_eq!(Register::from_u8(0x00), Some(Register::Status));
+    assert_eq!(Register::from_u32(0x08), Some(Register::Data));
+    assert_eq!(Register::Control.to_u8(), 0x04);
+    // Variants declared in the enum are kept
+    assert_eq!(Register::from_u8(0xFF), Some(Register::Reset));
+    assert_eq!(Register::from_u8(0x01), None);
+}
+
+// --- Test Case 62: Values colliding after a narrowing cast ---
+const OPCODE_BASE: isize = 0x40;
+
+// The values are distinct once cast, including the ones compared by a const assertion
+#[rawenum(u8, i16, bool, deny_collisions)]
+#[derive(Debug, PartialEq)]
+enum Opcode {
+    Nop = 0,
+    Halt = 1,
+    Load = OPCODE_BASE,
+    Store = OPCODE_BASE + 1,
+    Far = 0x200 + 2,
+}
+
+// `strict` keeps `Wide` from matching `u8`, so it doesn't collide with `Narrow`
+#[rawenum(u8, u16, strict, deny_collisions)]
+#[derive(Debug, PartialEq)]
+enum StrictOpcode {
+    Narrow = 0,
+    Wide = 0x100,
+}
+
+#[test]
+fn test_narrowing_collisions() {
+    assert_eq!(Opcode::from_u8(0x41), Some(Opcode::Store));
+    assert_eq!(Opcode::from_u8(2), Some(Opcode::Far));
+    assert_eq!(Opcode::from_bool(true), Some(Opcode::Halt));
+    assert_eq!(StrictOpcode::from_u8(0), Some(StrictOpcode::Narrow));
+    assert_eq!(StrictOpcode::from_u16(0x100), Some(StrictOpcode::Wide));
+}
+
+// --- Test Case 63: Rejecting lossy casts ---
+const ADJUSTMENT_STEP: isize = 0x10;
+
+// Every value, including the computed discriminants and the aliases, fits in both types
+#[rawenum(i8, i16, deny_lossy, impl_from)]
+#[derive(Debug, PartialEq)]
+enum Adjustment {
+    Up = ADJUSTMENT_STEP,
+    #[rawenum(alias = -128)]
+    Down = -ADJUSTMENT_STEP,
+    Hold = 0,
+}
+
+#[test]
+fn test_deny_lossy() {
+    assert_eq!(Adjustment::from_i8(-0x10), Some(Adjustment::Down));
+    assert_eq!(Adjustment::from_i8(-128), Some(Adjustment::Down));
+    assert_eq!(Adjustment::from_i16(0x10), Some(Adjustment::Up));
+    assert_eq!(i16::from(Adjustment::Hold), 0);
+}
+
+// --- Test Case 64: Strict and lossy conversions side by side ---
+#[rawenum(u8, i16, lossy_methods)]
+#[derive(Debug, PartialEq)]
+enum Transition {
+    Idle = 0,
+    Rise = 0x101,
+    Fall = -1,
+}
+
+#[test]
+fn test_lossy_methods() {
+    // The strict conversions only match the values the type can represent
+    assert_eq!(Transition::from_u8(0x01), None);
+    assert_eq!(Transition::from_u8(0xFF), None);
+    assert_eq!(Transition::from_i16(-1), Some(Transition::Fall));
+
+    // The lossy ones keep matching the values cast with wrapping
+    assert_eq!(Transition::from_u8_lossy(0x01), Some(Transition::Rise));
+    assert_eq!(Transition::from_u8_lossy(0xFF), Some(Transition::Fall));
+    assert_eq!(Transition::from_u8_lossy(0), Some(Transition::Idle));
+    assert_eq!(Transition::from_i16_lossy(0x101), Some(Transition::Rise));
+}
+
+// --- Test Case 65: Infallible conversions alongside the fallible ones ---
+byte_enum! {
+    #[rawenum(u8, i8, exhaustive_methods)]
+    #[derive(Debug, PartialEq)]
+    #[repr(u8)]
+    enum ByteOpcode
+}
+
+#[test]
+fn test_exhaustive_methods() {
+    // The exhaustive conversions are infallible and return Self directly
+    assert_eq!(ByteOpcode::from_u8_exhaustive(0), ByteOpcode::V0);
+    assert_eq!(ByteOpcode::from_u8_exhaustive(255), ByteOpcode::V255);
+    assert_eq!(ByteOpcode::from_i8_exhaustive(-1), ByteOpcode::V255);
+
+    // The other conversions still return an Option
+    assert_eq!(ByteOpcode::from_u8(0x80), Some(ByteOpcode::V128));
+}
+
+// --- Test Case 66: Contiguous discriminants matched by a range check ---
+#[rawenum(u8, i8, u16, i64)]
+#[derive(Debug, PartialEq)]
+#[repr(i16)]
+enum StackOp {
+    Push = -2,
+    Pop,
+    Add,
+    Sub,
+    Jump,
+}
+
+// Values outside the run convert to the catch-all variant
+#[rawenum(u8)]
+#[derive(Debug, PartialEq)]
+#[repr(u8)]
+enum Register8 {
+    #[rawenum(catch_all)]
+    Unknown = 0,
+    A,
+    B,
+    C,
+}
+
+#[test]
+fn test_contiguous_run() {
+    assert_eq!(StackOp::from_i8(-2), Some(StackOp::Push));
+    assert_eq!(StackOp::from_i64(2), Some(StackOp::Jump));
+    assert_eq!(StackOp::from_i64(3), None);
+    assert_eq!(StackOp::from_i64(-3), None);
+    // `u8` can't represent the whole run, so it still matches the wrapped values
+    assert_eq!(StackOp::from_u8(0xFE), Some(StackOp::Push));
+    assert_eq!(StackOp::from_u16(0), Some(StackOp::Add));
+    assert_eq!(StackOp::from_u16(0xFFFE), Some(StackOp::Push));
+
+    assert_eq!(Register8::from_u8(2), Register8::B);
+    assert_eq!(Register8::from_u8(0), Register8::Unknown);
+    assert_eq!(Register8::from_u8(200), Register8::Unknown);
+}
+
+// --- Test Case 67: Binary search over the sorted values ---
+const KERNEL_CALL_BASE: isize = 0x200;
+
+#[rawenum(
+    u16,
+    u8,
+    i64,
+    bool,
+    core::ffi::c_int,
+    strategy = "binary_search",
+    const
+)]
+#[derive(Debug, PartialEq)]
+enum KernelCall {
+    Exit = 60,
+    Read = 0,
+    #[rawenum(alias = 0x1001)]
+    Write = 1,
+    Mmap = KERNEL_CALL_BASE + 9,
+    Clone = KERNEL_CALL_BASE,
+}
+
+// With `strict`, the values the type can't represent are left out of the array
+#[rawenum(u8, i16, strict, strategy = "binary_search")]
+#[derive(Debug, PartialEq)]
+enum StrictKernelCall {
+    Exit = 60,
+    Far = 0x13C,
+}
+
+const KERNEL_CALL_WRITE: Option<KernelCall> = KernelCall::from_u16(1);
+
+#[test]
+fn test_binary_search_strategy() {
+    assert_eq!(KernelCall::from_u16(60), Some(KernelCall::Exit));
+    assert_eq!(KernelCall::from_u16(0x209), Some(KernelCall::Mmap));
+    assert_eq!(KernelCall::from_u16(0x200), Some(KernelCall::Clone));
+    assert_eq!(KernelCall::from_u16(0x1001), Some(KernelCall::Write));
+    assert_eq!(KernelCall::from_u16(2), None);
+    assert_eq!(KernelCall::from_i64(-1), None);
+    assert_eq!(KernelCall::from_c_int(0), Some(KernelCall::Read));
+    assert_eq!(KernelCall::from_bool(true), Some(KernelCall::Write));
+    assert_eq!(KERNEL_CALL_WRITE, Some(KernelCall::Write));
+
+    // 0x200 as u8 is 0, which `Read` claims first, and 0x209 as u8 is 9
+    assert_eq!(KernelCall::from_u8(0), Some(KernelCall::Read));
+    assert_eq!(KernelCall::from_u8(9), Some(KernelCall::Mmap));
+
+    assert_eq!(StrictKernelCall::from_u8(60), Some(StrictKernelCall::Exit));
+    assert_eq!(
+        StrictKernelCall::from_u8(0x3C),
+        Some(StrictKernelCall::Exit)
+    );
+    assert_eq!(
+        StrictKernelCall::from_i16(0x13C),
+        Some(StrictKernelCall::Far)
+    );
+}
+
+// --- Test Case 68: Strategy picked by the macro or by name ---
+// 40 values spread over 3901, so `auto` binary searches them
+#[rawenum(u16, i32, strategy = "auto")]
+#[derive(Debug, PartialEq)]
+enum SparsePort {
+    Port0 = 0,
+    Port1 = 100,
+    Port2 = 200,
+    Port3 = 300,
+    Port4 = 400,
+    Port5 = 500,
+    Port6 = 600,
+    Port7 = 700,
+    Port8 = 800,
+    Port9 = 900,
+    Port10 = 1000,
+    Port11 = 1100,
+    Port12 = 1200,
+    Port13 = 1300,
+    Port14 = 1400,
+    Port15 = 1500,
+    Port16 = 1600,
+    Port17 = 1700,
+    Port18 = 1800,
+    Port19 = 1900,
+    Port20 = 2000,
+    Port21 = 2100,
+    Port22 = 2200,
+    Port23 = 2300,
+    Port24 = 2400,
+    Port25 = 2500,
+    Port26 = 2600,
+    Port27 = 2700,
+    Port28 = 2800,
+    Port29 = 2900,
+    Port30 = 3000,
+    Port31 = 3100,
+    Port32 = 3200,
+    Port33 = 3300,
+    Port34 = 3400,
+    Port35 = 3500,
+    Port36 = 3600,
+    Port37 = 3700,
+    Port38 = 3800,
+    Port39 = 3900,
+}
+
+// Few values, so `auto` keeps the match
+#[rawenum(u8, strategy = "auto")]
+#[derive(Debug, PartialEq)]
+enum UnixSignal {
+    Hangup = 1,
+    Kill = 9,
+    Term = 15,
+}
+
+#[rawenum(u8, strategy = "table")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Parity {
+    None = 0,
+    Odd = 1,
+    Even = 2,
+}
+
+#[test]
+fn test_strategy_selector() {
+    assert_eq!(SparsePort::from_u16(0), Some(SparsePort::Port0));
+    assert_eq!(SparsePort::from_u16(2500), Some(SparsePort::Port25));
+    assert_eq!(SparsePort::from_i32(3900), Some(SparsePort::Port39));
+    assert_eq!(SparsePort::from_u16(2501), None);
+    assert_eq!(SparsePort::from_i32(-100), None);
+
+    assert_eq!(UnixSignal::from_u8(9), Some(UnixSignal::Kill));
+    assert_eq!(UnixSignal::from_u8(2), None);
+
+    assert_eq!(Parity::from_u8(2), Some(Parity::Even));
+    assert_eq!(Parity::from_u8(3), None);
+}
+
+// --- Test Case 69: Narrow types sharing the match of a large enum ---
+// The values fit in `u16`, so its conversions call those from `u64`
+#[rawenum(u16, i32, u64, named, contains)]
+#[derive(Debug, PartialEq)]
+enum BindingId {
+    V0 = 0,
+    V1 = 7,
+    V2 = 14,
+    V3 = 21,
+    V4 = 28,
+    V5 = 35,
+    V6 = 42,
+    V7 = 49,
+    V8 = 56,
+    V9 = 63,
+    V10 = 70,
+    V11 = 77,
+    V12 = 84,
+    V13 = 91,
+    V14 = 98,
+    V15 = 105,
+    V16 = 112,
+    V17 = 119,
+    V18 = 126,
+    V19 = 133,
+    V20 = 140,
+    V21 = 147,
+    V22 = 154,
+    V23 = 161,
+    V24 = 168,
+    V25 = 175,
+    V26 = 182,
+    V27 = 189,
+    V28 = 196,
+    V29 = 203,
+    V30 = 210,
+    V31 = 217,
+    V32 = 224,
+    V33 = 231,
+    V34 = 238,
+    V35 = 245,
+    V36 = 252,
+    V37 = 259,
+    V38 = 266,
+    V39 = 273,
+    V40 = 280,
+    V41 = 287,
+    V42 = 294,
+    V43 = 301,
+    V44 = 308,
+    V45 = 315,
+    V46 = 322,
+    V47 = 329,
+    V48 = 336,
+    V49 = 343,
+    V50 = 350,
+    V51 = 357,
+    V52 = 364,
+    V53 = 371,
+    V54 = 378,
+    V55 = 385,
+    V56 = 392,
+    V57 = 399,
+    V58 = 406,
+    V59 = 413,
+    V60 = 420,
+    V61 = 427,
+    V62 = 434,
+    V63 = 441,
+    V64 = 448,
+    V65 = 455,
+    V66 = 462,
+    V67 = 469,
+    V68 = 476,
+    V69 = 483,
+    V70 = 490,
+    V71 = 497,
+    V72 = 504,
+    V73 = 511,
+    V74 = 518,
+    V75 = 525,
+    V76 = 532,
+    V77 = 539,
+    V78 = 546,
+    V79 = 553,
+    V80 = 560,
+    V81 = 567,
+    V82 = 574,
+    V83 = 581,
+    V84 = 588,
+    V85 = 595,
+    V86 = 602,
+    V87 = 609,
+    V88 = 616,
+    V89 = 623,
+    V90 = 630,
+    V91 = 637,
+    V92 = 644,
+    V93 = 651,
+    V94 = 658,
+    V95 = 665,
+    V96 = 672,
+    V97 = 679,
+    V98 = 686,
+    V99 = 693,
+    V100 = 700,
+    V101 = 707,
+    V102 = 714,
+    V103 = 721,
+    V104 = 728,
+    V105 = 735,
+    V106 = 742,
+    V107 = 749,
+    V108 = 756,
+    V109 = 763,
+    V110 = 770,
+    V111 = 777,
+    V112 = 784,
+    V113 = 791,
+    V114 = 798,
+    V115 = 805,
+    V116 = 812,
+    V117 = 819,
+    V118 = 826,
+    V119 = 833,
+    V120 = 840,
+    V121 = 847,
+    V122 = 854,
+    V123 = 861,
+    V124 = 868,
+    V125 = 875,
+    V126 = 882,
+    V127 = 889,
+    V128 = 896,
+    V129 = 903,
+    V130 = 910,
+    V131 = 917,
+    V132 = 924,
+    V133 = 931,
+    V134 = 938,
+    V135 = 945,
+    V136 = 952,
+    V137 = 959,
+    V138 = 966,
+    V139 = 973,
+    V140 = 980,
+    V141 = 987,
+    V142 = 994,
+    V143 = 1001,
+    V144 = 1008,
+    V145 = 1015,
+    V146 = 1022,
+    V147 = 1029,
+    V148 = 1036,
+    V149 = 1043,
+    V150 = 1050,
+    V151 = 1057,
+    V152 = 1064,
+    V153 = 1071,
+    V154 = 1078,
+    V155 = 1085,
+    V156 = 1092,
+    V157 = 1099,
+    V158 = 1106,
+    V159 = 1113,
+    V160 = 1120,
+    V161 = 1127,
+    V162 = 1134,
+    V163 = 1141,
+    V164 = 1148,
+    V165 = 1155,
+    V166 = 1162,
+    V167 = 1169,
+    V168 = 1176,
+    V169 = 1183,
+    V170 = 1190,
+    V171 = 1197,
+    V172 = 1204,
+    V173 = 1211,
+    V174 = 1218,
+    V175 = 1225,
+    V176 = 1232,
+    V177 = 1239,
+    V178 = 1246,
+    V179 = 1253,
+    V180 = 1260,
+    V181 = 1267,
+    V182 = 1274,
+    V183 = 1281,
+    V184 = 1288,
+    V185 = 1295,
+    V186 = 1302,
+    V187 = 1309,
+    V188 = 1316,
+    V189 = 1323,
+    V190 = 1330,
+    V191 = 1337,
+    V192 = 1344,
+    V193 = 1351,
+    V194 = 1358,
+    V195 = 1365,
+    V196 = 1372,
+    V197 = 1379,
+    V198 = 1386,
+    V199 = 1393,
+    V200 = 1400,
+    V201 = 1407,
+    V202 = 1414,
+    V203 = 1421,
+    V204 = 1428,
+    V205 = 1435,
+    V206 = 1442,
+    V207 = 1449,
+    V208 = 1456,
+    V209 = 1463,
+    V210 = 1470,
+    V211 = 1477,
+    V212 = 1484,
+    V213 = 1491,
+    V214 = 1498,
+    V215 = 1505,
+    V216 = 1512,
+    V217 = 1519,
+    V218 = 1526,
+    V219 = 1533,
+    V220 = 1540,
+    V221 = 1547,
+    V222 = 1554,
+    V223 = 1561,
+    V224 = 1568,
+    V225 = 1575,
+    V226 = 1582,
+    V227 = 1589,
+    V228 = 1596,
+    V229 = 1603,
+    V230 = 1610,
+    V231 = 1617,
+    V232 = 1624,
+    V233 = 1631,
+    V234 = 1638,
+    V235 = 1645,
+    V236 = 1652,
+    V237 = 1659,
+    V238 = 1666,
+    V239 = 1673,
+    V240 = 1680,
+    V241 = 1687,
+    V242 = 1694,
+    V243 = 1701,
+    V244 = 1708,
+    V245 = 1715,
+    V246 = 1722,
+    V247 = 1729,
+    V248 = 1736,
+    V249 = 1743,
+    V250 = 1750,
+    V251 = 1757,
+    V252 = 1764,
+    V253 = 1771,
+    V254 = 1778,
+    V255 = 1785,
+    V256 = 1792,
+    V257 = 1799,
+    V258 = 1806,
+    V259 = 1813,
+    V260 = 1820,
+    V261 = 1827,
+    V262 = 1834,
+    V263 = 1841,
+    V264 = 1848,
+    V265 = 1855,
+    V266 = 1862,
+    V267 = 1869,
+    V268 = 1876,
+    V269 = 1883,
+    V270 = 1890,
+    V271 = 1897,
+    V272 = 1904,
+    V273 = 1911,
+    V274 = 1918,
+    V275 = 1925,
+    V276 = 1932,
+    V277 = 1939,
+    V278 = 1946,
+    V279 = 1953,
+    V280 = 1960,
+    V281 = 1967,
+    V282 = 1974,
+    V283 = 1981,
+    V284 = 1988,
+    V285 = 1995,
+    V286 = 2002,
+    V287 = 2009,
+    V288 = 2016,
+    V289 = 2023,
+    V290 = 2030,
+    V291 = 2037,
+    V292 = 2044,
+    V293 = 2051,
+    V294 = 2058,
+    V295 = 2065,
+    V296 = 2072,
+    V297 = 2079,
+    V298 = 2086,
+    V299 = 2093,
+}
+
+#[test]
+fn test_shared_match() {
+    assert_eq!(BindingId::from_u16(14), Some(BindingId::V2));
+    assert_eq!(BindingId::from_u16(2093), Some(BindingId::V299));
+    assert_eq!(BindingId::from_u16(15), None);
+    assert_eq!(BindingId::from_u16_named(7), Some((BindingId::V1, "V1")));
+    assert!(BindingId::contains_u16(700));
+    assert!(!BindingId::contains_u16(701));
+    assert_eq!(BindingId::from_i32(21), Some(BindingId::V3));
+    assert_eq!(BindingId::from_i32(-7), None);
+    assert_eq!(BindingId::from_u64(0), Some(BindingId::V0));
+    assert_eq!(BindingId::from_u64(2100), None);
+}
+
+// --- Test Case 70: Public associated consts per variant ---
+#[rawenum(u8, i32, consts)]
+#[derive(Debug, PartialEq)]
+enum HttpStatusClass {
+    Informational = 1,
+    Success = 2,
+    ClientError = 4,
+    ServerError = 5,
+}
+
+#[rawenum(u16, consts = "RAW_{VARIANT}")]
+#[derive(Debug, PartialEq)]
+enum TlsVersion {
+    Tls12 = 0x0303,
+    Tls13 = 0x0304,
+}
+
+#[test]
+fn test_value_consts() {
+    assert_eq!(HttpStatusClass::SUCCESS_U8, 2u8);
+    assert_eq!(HttpStatusClass::CLIENT_ERROR_I32, 4i32);
+    // The consts can be used as patterns and array lengths
+    let class = match 5u8 {
+        HttpStatusClass::CLIENT_ERROR_U8 => "client",
+        HttpStatusClass::SERVER_ERROR_U8 => "server",
+        _ => "other",
+    };
+    assert_eq!(class, "server");
+    let buffer = [0u8; HttpStatusClass::SERVER_ERROR_U8 as usize];
+    assert_eq!(buffer.len(), 5);
+
+    assert_eq!(TlsVersion::RAW_TLS12, 0x0303);
+    assert_eq!(TlsVersion::RAW_TLS13, TlsVersion::Tls13.to_u16());
+}
+
+// --- Test Case 71: Table of values and names ---
+#[rawenum(u8, i64, discriminants)]
+#[derive(Debug, PartialEq)]
+enum LogLevel {
+    Error = 1,
+    Warn = 2,
+    #[rawenum(alias = 4)]
+    Info = 3,
+    Debug = 10,
+}
+
+#[test]
+fn test_discriminants_const() {
+    assert_eq!(
+        LogLevel::DISCRIMINANTS_U8,
+        [(1, "Error"), (2, "Warn"), (3, "Info"), (10, "Debug")]
+    );
+    assert_eq!(LogLevel::DISCRIMINANTS_I64.len(), 4);
+    assert_eq!(LogLevel::DISCRIMINANTS_I64[3], (10i64, "Debug"));
+    // Every listed value converts back to its variant
+    for (value, name) in LogLevel::DISCRIMINANTS_U8 {
+        assert_eq!(format!("{:?}", LogLevel::from_u8(value).unwrap()), name);
+    }
+}
+
+// --- Test Case 72: Number of variants ---
+#[rawenum(u8, variant_count)]
+#[derive(Debug, PartialEq)]
+enum ColorChannel {
+    Red = 1,
+    Green = 2,
+    Blue = 4,
+    #[cfg(any())]
+    Alpha = 8,
+}
+
+#[rawenum(u16, variant_count)]
+#[derive(Debug, PartialEq)]
+#[repr(u16)]
+enum FallbackCode {
+    Known = 1,
+    #[rawenum(catch_all)]
+    Unknown(u16),
+}
+
+// An external table sized by the enum
+const CHANNEL_NAMES: [&str; ColorChannel::VARIANT_COUNT] = ["red", "green", "blue"];
+
+#[test]
+fn test_variant_count() {
+    assert_eq!(ColorChannel::VARIANT_COUNT, 3);
+    assert_eq!(CHANNEL_NAMES.len(), ColorChannel::VARIANT_COUNT);
+    assert_eq!(FallbackCode::VARIANT_COUNT, 2);
+}
+
+// --- Test Case 73: Smallest and largest discriminants ---
+#[rawenum(i8, u8, discriminant_range)]
+#[derive(Debug, PartialEq)]
+enum ClimateBand {
+    Freezing = -10,
+    Mild = 15,
+    #[rawenum(wire = 40)]
+    Hot = 30,
+}
+
+#[test]
+fn test_discriminant_range() {
+    assert_eq!(ClimateBand::MIN_DISCRIMINANT, -10i128);
+    // The `wire` value is the value of the variant
+    assert_eq!(ClimateBand::MAX_DISCRIMINANT, 40i128);
+    assert_eq!(ClimateBand::MIN_DISCRIMINANT_I8, -10i8);
+    assert_eq!(ClimateBand::MAX_DISCRIMINANT_I8, 40i8);
+    // -10 wraps around to 246 once cast to `u8`
+    assert_eq!(ClimateBand::MIN_DISCRIMINANT_U8, 15u8);
+    assert_eq!(ClimateBand::MAX_DISCRIMINANT_U8, 246u8);
+    let lookup = [0u8; ClimateBand::MAX_DISCRIMINANT_U8 as usize + 1];
+    assert_eq!(lookup.len(), 247);
+}
+
+// --- Test Case 74: Variant names without `Debug` ---
+#[rawenum(u8, variant_name)]
+#[repr(u8)]
+enum LinkState {
+    Down = 0,
+    r#Up = 1,
+    #[cfg(any())]
+    Testing = 2,
+    #[rawenum(catch_all)]
+    Other(u8),
+}
+
+const DOWN_NAME: &str = LinkState::Down.variant_name();
+
+#[test]
+fn test_variant_name() {
+    assert_eq!(DOWN_NAME, "Down");
+    assert_eq!(LinkState::Up.variant_name(), "Up");
+    assert_eq!(LinkState::from_u8(7).variant_name(), "Other");
+}
+
+// --- Test Case 75: Variant names looked up by value ---
+#[rawenum(u8, i32, bool, name_of, variant_name)]
+#[derive(Debug, PartialEq)]
+enum DiagCode {
+    Ok = 0,
+    #[rawenum(alias = 3)]
+    Overheat = 1,
+    Undervolt = 0x102,
+}
+
+#[test]
+fn test_name_of() {
+    assert_eq!(DiagCode::name_of_u8(1), Some("Overheat"));
+    assert_eq!(DiagCode::name_of_u8(3), Some("Overheat"));
+    // 0x102 as u8 is 2
+    assert_eq!(DiagCode::name_of_u8(2), Some("Undervolt"));
+    assert_eq!(DiagCode::name_of_i32(0x102), Some("Undervolt"));
+    assert_eq!(DiagCode::name_of_i32(2), None);
+    assert_eq!(DiagCode::name_of_bool(false), Some("Ok"));
+    // The names are the ones `variant_name` returns
+    for variant in [DiagCode::Ok, DiagCode::Overheat, DiagCode::Undervolt] {
+        assert_eq!(
+            DiagCode::name_of_i32(variant.to_i32()),
+            Some(variant.variant_name())
+        );
+    }
+}
+
+// --- Test Case 76: Helpers of total conversions report invalid values ---
+#[rawenum(
+    u8,
+    u16,
+    total,
+    control_flow,
+    slice_helpers,
+    result,
+    traits,
+    num_enum,
+    decode(shift = 4, mask = 0xF)
+)]
+#[repr(u8)]
+#[derive(Debug, PartialEq)]
+enum Beacon {
+    Idle = 1,
+    Active = 2,
+}
+
+#[test]
+fn test_total_helpers() {
+    use rawenum_core::FromRaw;
+    use std::ops::ControlFlow;
+
+    assert_eq!(Beacon::from_u8(2), Beacon::Active);
+    assert_eq!(Beacon::from_u8_flow(99), ControlFlow::Break(99));
+    assert_eq!(Beacon::from_any_u8(&[99, 1]), Some(Beacon::Idle));
+    assert!(Beacon::try_from_u16(99).is_err());
+    assert_eq!(Beacon::decode_u16(0x7F), None);
+    assert_eq!(Beacon::decode_u16(0x2F), Some((Beacon::Active, 0xF)));
+    assert_eq!(<Beacon as FromRaw<u8>>::from_raw(99), None);
+    assert_eq!(Beacon::try_from_primitive(99).unwrap_err().number, 99);
+}
+
+#[test]
+#[should_panic(expected = "invalid discriminant 99")]
+fn test_total_conversion_panics() {
+    let _ = Beacon::from_u8(99);
+}
+
+// --- Test Case 77: Lookup table with `Copy` the macro can't see ---
+// An attribute macro never sees the derives listed before it
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[rawenum(u8, table)]
+enum ShadeBefore {
+    Light = 1,
+    Dark = 2,
+}
+
+#[rawenum(u8, table)]
+#[derive(Debug, PartialEq)]
+enum ShadeManual {
+    Light = 1,
+    Dark = 2,
+}
+
+impl Clone for ShadeManual {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for ShadeManual {}
+
+#[test]
+fn test_table_copy_check() {
+    assert_eq!(ShadeBefore::from_u8(2), Some(ShadeBefore::Dark));
+    assert_eq!(ShadeManual::from_u8(1), Some(ShadeManual::Light));
+    assert_eq!(ShadeManual::from_u8(3), None);
+}
+
+// --- Test Case 78: Catch-all variant holding every value of the types ---
+// `i32` holds every `u16` and `i8`, so unmatched values keep their sign and width
+#[rawenum(u16, i8)]
+#[derive(Debug, PartialEq)]
+#[repr(i32)]
+enum Reply {
+    Ack = 1,
+    #[rawenum(catch_all)]
+    Other(i32),
+}
+
+#[test]
+fn test_catch_all_wide_payload() {
+    assert_eq!(Reply::from_u16(1), Reply::Ack);
+    assert_eq!(Reply::from_u16(0xFFFF), Reply::Other(0xFFFF));
+    assert_eq!(Reply::from_i8(-1), Reply::Other(-1));
+}
+
+// --- Test Case 79: Decoding a tag field some types are too narrow for ---
+// The tag is the high byte of a 16-bit word, so `decode_u8` isn't generated
+#[rawenum(u8, i16, u32, decode(shift = 8, mask = 0xFF))]
+#[derive(Debug, PartialEq)]
+enum HighTag {
+    Low = 0x01,
+    High = 0x80,
+}
+
+#[test]
+fn test_decode_skips_narrow_types() {
+    assert_eq!(HighTag::decode_u32(0x0105), Some((HighTag::Low, 0x05)));
+    // The sign bits of the arithmetic shift are masked off
+    assert_eq!(
+        HighTag::decode_i16(0x8005_u16 as i16),
+        Some((HighTag::High, 0x05))
+    );
+    assert_eq!(HighTag::from_u8(0x80), Some(HighTag::High));
+}
+
+// --- Test Case 80: Enums the module-level attribute leaves alone ---
+#[rawenum_mod(u8)]
+mod frame_types {
+    #[derive(Debug, PartialEq)]
+    pub enum Kind {
+        Data = 0,
+        Ack = 1,
+    }
+
+    // Holds fields, so it can't be converted from an integer
+    #[derive(Debug, PartialEq)]
+    pub enum Frame {
+        Data(Vec<u8>),
+        Ack(u32),
+    }
+
+    // Opted out, even though it could be converted
+    #[rawenum(skip)]
+    #[derive(Debug, PartialEq)]
+    pub enum Priority {
+        Low = 0,
+        High = 1,
+    }
+
+    // A catch-all variant holding the unmatched value is still converted
+    #[derive(Debug, PartialEq)]
+    #[repr(u8)]
+    pub enum Flag {
+        Urgent = 1,
+        #[rawenum(catch_all)]
+        Other(u8),
+    }
+}
+
+// Stands in for conversions that would otherwise clash with the generated ones
+impl frame_types::Priority {
+    fn from_u8(value: u8) -> Self {
+        if value == 0 { Self::Low } else { Self::High }
+    }
+}
+
+#[test]
+fn test_module_level_attribute_skips() {
+    use frame_types::{Flag, Frame, Kind, Priority};
+
+    assert_eq!(Kind::from_u8(1), Some(Kind::Ack));
+    assert_ne!(Frame::Data(vec![3]), Frame::Ack(3));
+    assert_eq!(Priority::from_u8(7), Priority::High);
+    assert_eq!(Flag::from_u8(9), Flag::Other(9));
+}
+
+// --- Test Case 81: Generated round-trip tests ---
+// The generated tests check every variant, here with implicit discriminants
+#[rawenum(i16, u64, roundtrip_test)]
+#[derive(Debug, PartialEq)]
+enum RoundTripEnum {
+    A,
+    B,
+    C,
+}
+
+// With `exhaustive`, the 8-bit conversions round-trip without an `Option`
+byte_enum! {
+    #[rawenum(u8, i32, exhaustive, roundtrip_test)]
+    #[derive(Debug, PartialEq)]
+    #[repr(u8)]
+    enum RoundTripByte
+}
+
+// --- Test Case 82: Generated property tests ---
+// Negative values wrap when cast to `u8`
+#[rawenum(i8, u8, property_test)]
+#[derive(Debug, PartialEq)]
+enum PropertyEnum {
+    NegOne = -1,
+    NegTwo = -2,
+    Zero = 0,
+    One = 1,
+}
+
+// Every `u8` matches a variant
+byte_enum! {
+    #[rawenum(u8, i32, exhaustive, property_test)]
+    #[derive(Debug, PartialEq)]
+    #[repr(u8)]
+    enum PropertyByte
+}
+
+// The wire values are checked in place of the discriminants
+#[rawenum(u8, i16, property_test)]
+#[derive(Debug, PartialEq)]
+enum PropertyWire {
+    Nop = 0,
+    #[rawenum(wire = 0xFF)]
+    Halt = 1,
+    #[rawenum(wire = -2)]
+    Reset = 2,
+}
+
+// --- Test Case 83: Values matched by literal patterns ---
+// The aliases of `Low` follow each other, so they're matched by a single range
+#[rawenum(i8, u8, bool)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    #[rawenum(alias = -3, alias = -4)]
+    Low = -2,
+    Off = 0,
+    On = 1,
+}
+
+// Negative values wrap above `i128::MAX` as `u128`
+#[rawenum(u128, i128)]
+#[derive(Debug, PartialEq)]
+enum Sentinel {
+    Start = 0,
+    #[rawenum(alias = -1)]
+    End = -2,
+}
+
+// `Rare` is gated behind a `cfg`, so the conversions keep the consts
+#[rawenum(u8)]
+#[derive(Debug, PartialEq)]
+enum Gated {
+    Common = 1,
+    #[cfg(any())]
+    Rare = 2,
+}
+
+#[test]
+fn test_literal_patterns() {
+    assert_eq!(Level::from_i8(-4), Some(Level::Low));
+    assert_eq!(Level::from_i8(-3), Some(Level::Low));
+    assert_eq!(Level::from_i8(-2), Some(Level::Low));
+    assert_eq!(Level::from_i8(-5), None);
+    assert_eq!(Level::from_i8(-1), None);
+    assert_eq!(Level::from_u8(252), Some(Level::Low)); // -4 as u8 is 252
+    assert_eq!(Level::from_bool(false), Some(Level::Off));
+    assert_eq!(Level::from_bool(true), Some(Level::On));
+
+    assert_eq!(Sentinel::from_u128(u128::MAX), Some(Sentinel::End));
+    assert_eq!(Sentinel::from_u128(u128::MAX - 1), Some(Sentinel::End));
+    assert_eq!(Sentinel::from_u128(u128::MAX - 2), None);
+    assert_eq!(Sentinel::from_u128(0), Some(Sentinel::Start));
+    assert_eq!(Sentinel::from_i128(-1), Some(Sentinel::End));
+
+    assert_eq!(Gated::from_u8(1), Some(Gated::Common));
+    assert_eq!(Gated::from_u8(2), None);
+}
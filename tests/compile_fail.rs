@@ -0,0 +1,9 @@
+// Tests for code that the macro is expected to reject (or warn about), with the
+// expected compiler output stored next to each case in `tests/ui`, along with the
+// code it is expected to accept, in `tests/ui/pass`.
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
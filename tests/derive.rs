@@ -0,0 +1,59 @@
+use rawenum::RawEnum;
+
+#[derive(RawEnum, Debug, PartialEq)]
+#[rawenum(types(u8, i32), named, try_from)]
+enum Opcode {
+    Nop = 0x90,
+    #[rawenum(alias = 0xC2)]
+    Ret = 0xC3,
+}
+
+#[derive(RawEnum, Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+enum Port {
+    Http = 80,
+    Https = 443,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(all(), derive(RawEnum))]
+#[cfg_attr(all(), rawenum(types(u8), module = raw))]
+enum Mode {
+    Read = 1,
+    Write = 2,
+}
+
+#[test]
+fn test_opcode_derive() {
+    assert_eq!(Opcode::from_u8(0x90), Some(Opcode::Nop));
+    assert_eq!(Opcode::from_i32(0xC2), Some(Opcode::Ret));
+    assert_eq!(Opcode::from_u8_named(0xC3), Some((Opcode::Ret, "Ret")));
+    assert_eq!(Opcode::try_from(0x90u8), Ok(Opcode::Nop));
+    assert_eq!(Opcode::Ret.to_u8(), 0xC3);
+}
+
+#[test]
+fn test_port_derive_repr() {
+    // Without a helper attribute, the type is read from the repr
+    assert_eq!(Port::from_u16(443), Some(Port::Https));
+    assert_eq!(Port::Http.to_u16(), 80);
+}
+
+#[test]
+fn test_mode_derive_cfg_attr() {
+    assert_eq!(raw::from_u8(2), Some(Mode::Write));
+}
+
+// The derive's input never holds the derives, so `Copy` is checked by the compiler
+#[derive(RawEnum, Debug, PartialEq, Clone, Copy)]
+#[rawenum(types(u8), table)]
+enum Lane {
+    Left = 1,
+    Right = 2,
+}
+
+#[test]
+fn test_lane_derive_table() {
+    assert_eq!(Lane::from_u8(2), Some(Lane::Right));
+    assert_eq!(Lane::from_u8(0), None);
+}
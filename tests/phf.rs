@@ -0,0 +1,57 @@
+use rawenum::rawenum;
+
+// The lookup is backed by a perfect hash map with the `phf` feature, and by the bucketed
+// match without it, so these tests check both
+#[rawenum(u8, name_lookup, phf)]
+#[derive(Debug, PartialEq)]
+enum Keyword {
+    Let,
+    Fn,
+    Struct,
+    Enum,
+    Impl,
+    r#Match,
+}
+
+#[test]
+fn test_keyword_variant_for_name() {
+    assert_eq!(Keyword::variant_for_name("Let"), Some(Keyword::Let));
+    assert_eq!(Keyword::variant_for_name("Fn"), Some(Keyword::Fn));
+    assert_eq!(Keyword::variant_for_name("Struct"), Some(Keyword::Struct));
+    assert_eq!(Keyword::variant_for_name("Enum"), Some(Keyword::Enum));
+    assert_eq!(Keyword::variant_for_name("Impl"), Some(Keyword::Impl));
+    assert_eq!(Keyword::variant_for_name("Match"), Some(Keyword::Match));
+    assert_eq!(Keyword::variant_for_name("let"), None); // Names are case sensitive
+    assert_eq!(Keyword::variant_for_name("Trait"), None); // Unknown name
+    assert_eq!(Keyword::variant_for_name(""), None);
+}
+
+// The conversions are backed by a perfect hash map with the `phf` feature
+#[rawenum(u16, i32, u8, u128, usize, strategy = "phf")]
+#[derive(Debug, PartialEq)]
+enum UsbVendor {
+    Intel = 0x8086,
+    #[rawenum(alias = 0x05AD)]
+    Apple = 0x05AC,
+    Logitech = 0x046D,
+    #[rawenum(wire = -1)]
+    Unknown = 0x7FFF,
+}
+
+#[test]
+fn test_usb_vendor_phf_strategy() {
+    assert_eq!(UsbVendor::from_u16(0x8086), Some(UsbVendor::Intel));
+    assert_eq!(UsbVendor::from_u16(0x05AD), Some(UsbVendor::Apple));
+    assert_eq!(UsbVendor::from_u16(0xFFFF), Some(UsbVendor::Unknown));
+    assert_eq!(UsbVendor::from_u16(0x7FFF), None);
+    assert_eq!(UsbVendor::from_i32(-1), Some(UsbVendor::Unknown));
+    assert_eq!(UsbVendor::from_i32(0xFFFF), None);
+    assert_eq!(UsbVendor::from_u128(u128::MAX), Some(UsbVendor::Unknown));
+    assert_eq!(UsbVendor::from_usize(0x046D), Some(UsbVendor::Logitech));
+
+    // The values are cast to each type, so 0x8086 as u8 is 0x86
+    assert_eq!(UsbVendor::from_u8(0x86), Some(UsbVendor::Intel));
+    assert_eq!(UsbVendor::from_u8(0xAC), Some(UsbVendor::Apple));
+    assert_eq!(UsbVendor::from_u8(0xFF), Some(UsbVendor::Unknown));
+    assert_eq!(UsbVendor::from_u8(0x00), None);
+}
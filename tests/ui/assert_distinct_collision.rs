@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, assert_distinct)]
+enum CollidingEnum {
+    Zero = 0,
+    Wrapped = 256,
+}
+
+const _: () = assert!(CollidingEnum::assert_distinct_u8());
+
+fn main() {}
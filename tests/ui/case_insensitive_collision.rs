@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, name_lookup, case_insensitive)]
+enum CaseCollidingEnum {
+    Http,
+    HTTP,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+#[rawenum(u16)]
+#[repr(u16)]
+enum Status {
+    Ok = 0,
+    #[rawenum(catch_all)]
+    Other(String),
+}
+
+fn main() {}
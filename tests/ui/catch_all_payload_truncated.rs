@@ -0,0 +1,12 @@
+use rawenum::rawenum;
+
+// `from_u32` would truncate unmatched values into the `u8`
+#[rawenum(u8, u32)]
+#[repr(u8)]
+enum Status {
+    Ok = 0,
+    #[rawenum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+#[rawenum(u32)]
+enum NoReprEnum {
+    A,
+    B,
+    #[rawenum(catch_all)]
+    Other(u32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, iter)]
+enum CfgVariantEnum {
+    A = 1,
+    #[cfg(any())]
+    B = 2,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, i32, consts = "RAW_{VARIANT}")]
+enum Status {
+    Ok = 0,
+    Failed = 1,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+// Bits 8..16 are past the end of a `u8`
+#[rawenum(u8, decode(shift = 8, mask = 0xFF))]
+enum Tag {
+    A = 1,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rawenum::rawenum;
+
+#[rawenum(i8, u8, deny_lossy)]
+enum Adjustment {
+    Up = 5,
+    // -5 as u8 is 251
+    Down = -5,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+use rawenum::rawenum;
+
+#[rawenum(u8)]
+enum DeprecatedEnum {
+    Current = 1,
+    #[deprecated(note = "use `Current` instead")]
+    Legacy = 2,
+}
+
+fn main() {
+    let _ = DeprecatedEnum::from_u8(1);
+}
@@ -0,0 +1,12 @@
+use rawenum::rawenum;
+
+#[rawenum(u8)]
+enum DuplicateCatchAllEnum {
+    A = 1,
+    #[rawenum(catch_all)]
+    Unknown = 2,
+    #[rawenum(catch_all)]
+    Other = 3,
+}
+
+fn main() {}
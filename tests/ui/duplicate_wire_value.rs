@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+#[rawenum(u8)]
+enum DuplicateWireEnum {
+    #[rawenum(wire = 0xFF)]
+    A,
+    #[rawenum(wire = 255)]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, exhaustive_methods)]
+enum PartialOpcode {
+    Nop = 0,
+    Halt = 1,
+}
+
+fn main() {}
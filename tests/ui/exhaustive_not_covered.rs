@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, exhaustive)]
+enum PartialByteEnum {
+    A = 0,
+    B = 1,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, impl_from)]
+enum TruncatingEnum {
+    Small = 1,
+    Large = 0x100,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![deny(unused_must_use)]
+
+use rawenum::rawenum;
+
+#[rawenum(u8)]
+enum MustUseEnum {
+    A = 1,
+}
+
+fn main() {
+    MustUseEnum::from_u8(1);
+}
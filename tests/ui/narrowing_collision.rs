@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, i32, deny_collisions)]
+enum Opcode {
+    Nop = 0,
+    Halt = 1,
+    // 0x100 as u8 is 0, the value of `Nop`
+    Jump = 0x100,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use rawenum::rawenum;
+
+const BASE: isize = 0x100;
+
+// The discriminants aren't literals, so they're compared by a const assertion
+#[rawenum(u8, i32, deny_collisions)]
+enum Opcode {
+    Nop = 0,
+    Jump = BASE,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+#[rawenum(u8)]
+enum Kind {
+    A = 1,
+}
+
+#[rawenum(u8, table)]
+struct Wrapper(Kind);
+
+fn main() {}
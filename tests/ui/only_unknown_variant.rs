@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, only(A, Missing))]
+enum OnlyEnum {
+    A = 1,
+    B = 2,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use rawenum::rawenum;
+
+#[rawenum(strict_types, i16, i32, c_int)]
+#[derive(Debug, PartialEq)]
+enum StrictEnum {
+    Negative = -1,
+    Zero,
+    Large = 256,
+    #[rawenum(wire = 0x7FFF)]
+    Wire,
+}
+
+fn main() {
+    assert_eq!(StrictEnum::from_i16(0), Some(StrictEnum::Zero));
+    assert_eq!(StrictEnum::from_i32(256), Some(StrictEnum::Large));
+    assert_eq!(StrictEnum::from_c_int(0x7FFF), Some(StrictEnum::Wire));
+}
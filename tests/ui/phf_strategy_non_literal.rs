@@ -0,0 +1,11 @@
+use rawenum::rawenum;
+
+const BASE: isize = 0x100;
+
+#[rawenum(u16, strategy = "phf")]
+enum Vendor {
+    First = BASE,
+    Second = BASE + 1,
+}
+
+fn main() {}
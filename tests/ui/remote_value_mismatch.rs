@@ -0,0 +1,17 @@
+use rawenum::rawenum_remote;
+
+mod foreign {
+    pub enum Status {
+        Ok = 0,
+        Err = 1,
+    }
+}
+
+rawenum_remote! {
+    for foreign::Status as u32 {
+        Ok = 0,
+        Err = 2,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(repr)]
+enum NoReprEnum {
+    A = 1,
+    B = 2,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(strict_types, i8, i16, i32)]
+enum StrictEnum {
+    Small = 1,
+    Large = 256,
+}
+
+fn main() {}
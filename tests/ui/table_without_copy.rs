@@ -0,0 +1,10 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, table)]
+#[derive(Debug, PartialEq)]
+enum NotCopyEnum {
+    A,
+    B,
+}
+
+fn main() {}
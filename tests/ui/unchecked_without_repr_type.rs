@@ -0,0 +1,10 @@
+use rawenum::rawenum;
+
+#[rawenum(i32, unchecked)]
+#[repr(u8)]
+enum UncheckedEnum {
+    A = 1,
+    B = 2,
+}
+
+fn main() {}
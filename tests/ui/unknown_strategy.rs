@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u8, strategy = "jump_table")]
+enum Signal {
+    Hangup = 1,
+    Kill = 9,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(int)]
+enum MyEnum {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rawenum::rawenum;
+
+#[rawenum(u7)]
+enum MyEnum {
+    A,
+    B,
+}
+
+fn main() {}